@@ -0,0 +1,137 @@
+use crate::Color;
+use crate::color::space::{from_unit, unit_channels};
+
+/// How a source color is combined with the backdrop it is drawn onto.
+///
+/// ## Porter-Duff operators
+///
+/// [`Over`], [`In`], [`Out`], [`Atop`] and [`Xor`] decide which parts of the source
+/// and backdrop remain visible based on their coverage (alpha), following Porter and
+/// Duff's compositing algebra.
+///
+/// ## Blend modes
+///
+/// [`Multiply`], [`Screen`], [`Overlay`], [`Darken`], [`Lighten`] and [`Difference`]
+/// mix the source and backdrop colors where they overlap, then composite the result
+/// with source-over, following the W3C Compositing and Blending specification.
+///
+/// All modes operate on premultiplied alpha internally, so partially transparent
+/// colors composite correctly.
+///
+/// [`Over`]: CompositeMode::Over
+/// [`In`]: CompositeMode::In
+/// [`Out`]: CompositeMode::Out
+/// [`Atop`]: CompositeMode::Atop
+/// [`Xor`]: CompositeMode::Xor
+/// [`Multiply`]: CompositeMode::Multiply
+/// [`Screen`]: CompositeMode::Screen
+/// [`Overlay`]: CompositeMode::Overlay
+/// [`Darken`]: CompositeMode::Darken
+/// [`Lighten`]: CompositeMode::Lighten
+/// [`Difference`]: CompositeMode::Difference
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum CompositeMode {
+    /// The source is drawn on top of the backdrop.
+    #[default]
+    Over,
+    /// Only the source is shown, where it overlaps the backdrop.
+    In,
+    /// Only the source is shown, where it does not overlap the backdrop.
+    Out,
+    /// The source is drawn on top of the backdrop, only where the backdrop exists.
+    Atop,
+    /// Source and backdrop are shown only where they do not overlap.
+    Xor,
+    /// Multiplies channels, always darkening. Multiplying with white has no effect.
+    Multiply,
+    /// Inverse multiply, always lightening. Screening with black has no effect.
+    Screen,
+    /// Multiplies dark backdrop areas and screens light ones, increasing contrast.
+    Overlay,
+    /// Keeps the darker of the two channels.
+    Darken,
+    /// Keeps the lighter of the two channels.
+    Lighten,
+    /// Absolute difference between the channels.
+    Difference,
+}
+
+impl Color {
+    /// Composites `source` onto this color, treating `self` as the backdrop.
+    ///
+    /// Unlike [`blend`](Color::blend), which interpolates all four channels, this
+    /// performs true alpha compositing: a half-transparent source over an opaque
+    /// backdrop yields an opaque result, and transparent regions contribute nothing.
+    pub fn composite(self, source: Color, mode: CompositeMode) -> Self {
+        let [sr, sg, sb, sa] = unit_channels(source);
+        let [br, bg, bb, ba] = unit_channels(self);
+
+        let (fa, fb) = match mode {
+            CompositeMode::In => (ba, 0.0),
+            CompositeMode::Out => (1.0 - ba, 0.0),
+            CompositeMode::Atop => (ba, 1.0 - sa),
+            CompositeMode::Xor => (1.0 - ba, 1.0 - sa),
+            _ => (1.0, 1.0 - sa),
+        };
+
+        // Blend modes mix the source with the backdrop where the backdrop is present.
+        let mix = |cs: f32, cb: f32| match blend_channel(mode, cb, cs) {
+            Some(blended) => (1.0 - ba) * cs + ba * blended,
+            None => cs,
+        };
+        let source_rgb = [mix(sr, br), mix(sg, bg), mix(sb, bb)];
+
+        let alpha = sa * fa + ba * fb;
+        if alpha <= 0.0 {
+            return Self::new(0, 0, 0, 0);
+        }
+
+        let channel = |cs: f32, cb: f32| (cs * sa * fa + cb * ba * fb) / alpha;
+
+        from_unit([
+            channel(source_rgb[0], br),
+            channel(source_rgb[1], bg),
+            channel(source_rgb[2], bb),
+            alpha,
+        ])
+    }
+
+    /// Interpolates from this color toward `target` by `opacity` with premultiplied
+    /// alpha, the way a layer's opacity fades its composited result into the backdrop.
+    pub(crate) fn mix_premultiplied(self, target: Color, opacity: u8) -> Self {
+        let t = opacity as f32 / 255.0;
+        let [fr, fg, fb, fa] = unit_channels(self);
+        let [tr, tg, tb, ta] = unit_channels(target);
+
+        let alpha = fa * (1.0 - t) + ta * t;
+        if alpha <= 0.0 {
+            return Self::new(0, 0, 0, 0);
+        }
+
+        let channel = |cf: f32, ct: f32| (cf * fa * (1.0 - t) + ct * ta * t) / alpha;
+
+        from_unit([channel(fr, tr), channel(fg, tg), channel(fb, tb), alpha])
+    }
+}
+
+/// The separable blend function `B(cb, cs)`, or `None` for Porter-Duff operators.
+fn blend_channel(mode: CompositeMode, cb: f32, cs: f32) -> Option<f32> {
+    let multiply = |a: f32, b: f32| a * b;
+    let screen = |a: f32, b: f32| a + b - a * b;
+
+    Some(match mode {
+        CompositeMode::Multiply => multiply(cb, cs),
+        CompositeMode::Screen => screen(cb, cs),
+        CompositeMode::Overlay => {
+            if cb <= 0.5 {
+                multiply(cs, 2.0 * cb)
+            } else {
+                screen(cs, 2.0 * cb - 1.0)
+            }
+        }
+        CompositeMode::Darken => cb.min(cs),
+        CompositeMode::Lighten => cb.max(cs),
+        CompositeMode::Difference => (cb - cs).abs(),
+        _ => return None,
+    })
+}
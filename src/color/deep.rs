@@ -0,0 +1,165 @@
+use image::Rgba;
+
+use crate::Color;
+
+/// An RGBA color with 16 bits per channel, for images such as `Rgba16` and `Rgb16`.
+///
+/// Converting an 8-bit [`Color`] to `Color16` is exact (each channel is scaled by 257);
+/// converting back rounds to the nearest 8-bit value.
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub struct Color16([u16; 4]);
+
+/// An RGBA color with `f32` channels, for images such as `Rgba32F` and `Rgb32F`.
+///
+/// Channels are nominally in `[0, 1]`, but values outside that range (such as HDR
+/// intensities) are preserved until converted to an integer color, where they are clamped.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct ColorF32([f32; 4]);
+
+impl Color16 {
+    /// Creates a `Color16` from RGBA components.
+    pub const fn new(r: u16, g: u16, b: u16, a: u16) -> Self {
+        Self([r, g, b, a])
+    }
+
+    /// Returns the red channel value.
+    pub fn r(self) -> u16 {
+        self.0[0]
+    }
+
+    /// Returns the green channel value.
+    pub fn g(self) -> u16 {
+        self.0[1]
+    }
+
+    /// Returns the blue channel value.
+    pub fn b(self) -> u16 {
+        self.0[2]
+    }
+
+    /// Returns the alpha channel value.
+    pub fn a(self) -> u16 {
+        self.0[3]
+    }
+
+    /// Returns a new color with the same RGB values but a different alpha channel.
+    pub fn with_alpha(self, a: u16) -> Self {
+        Self([self.0[0], self.0[1], self.0[2], a])
+    }
+}
+
+impl ColorF32 {
+    /// Creates a `ColorF32` from RGBA components.
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self([r, g, b, a])
+    }
+
+    /// Returns the red channel value.
+    pub fn r(self) -> f32 {
+        self.0[0]
+    }
+
+    /// Returns the green channel value.
+    pub fn g(self) -> f32 {
+        self.0[1]
+    }
+
+    /// Returns the blue channel value.
+    pub fn b(self) -> f32 {
+        self.0[2]
+    }
+
+    /// Returns the alpha channel value.
+    pub fn a(self) -> f32 {
+        self.0[3]
+    }
+
+    /// Returns a new color with the same RGB values but a different alpha channel.
+    pub fn with_alpha(self, a: f32) -> Self {
+        Self([self.0[0], self.0[1], self.0[2], a])
+    }
+}
+
+impl From<Color> for Color16 {
+    fn from(color: Color) -> Self {
+        Self(<[u8; 4]>::from(color).map(|c| c as u16 * 257))
+    }
+}
+
+impl From<Color16> for Color {
+    fn from(color: Color16) -> Self {
+        color.0.map(|c| ((c as u32 + 128) / 257) as u8).into()
+    }
+}
+
+impl From<Color> for ColorF32 {
+    fn from(color: Color) -> Self {
+        Self(<[u8; 4]>::from(color).map(|c| c as f32 / 255.0))
+    }
+}
+
+impl From<ColorF32> for Color {
+    fn from(color: ColorF32) -> Self {
+        color.0.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8).into()
+    }
+}
+
+impl From<Color16> for ColorF32 {
+    fn from(color: Color16) -> Self {
+        Self(color.0.map(|c| c as f32 / u16::MAX as f32))
+    }
+}
+
+impl From<ColorF32> for Color16 {
+    fn from(color: ColorF32) -> Self {
+        Self(color.0.map(|c| (c.clamp(0.0, 1.0) * u16::MAX as f32).round() as u16))
+    }
+}
+
+impl From<Rgba<u16>> for Color16 {
+    fn from(value: Rgba<u16>) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<Color16> for Rgba<u16> {
+    fn from(value: Color16) -> Self {
+        Rgba(value.0)
+    }
+}
+
+impl From<Rgba<f32>> for ColorF32 {
+    fn from(value: Rgba<f32>) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<ColorF32> for Rgba<f32> {
+    fn from(value: ColorF32) -> Self {
+        Rgba(value.0)
+    }
+}
+
+impl From<[u16; 4]> for Color16 {
+    fn from(value: [u16; 4]) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Color16> for [u16; 4] {
+    fn from(value: Color16) -> Self {
+        value.0
+    }
+}
+
+impl From<[f32; 4]> for ColorF32 {
+    fn from(value: [f32; 4]) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ColorF32> for [f32; 4] {
+    fn from(value: ColorF32) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,149 @@
+use crate::Color;
+use crate::color::Lab;
+
+/// A method of measuring how different two colors are.
+///
+/// The alpha channel is ignored by every metric. Distances are only comparable
+/// within the same metric, since each has its own scale.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum ColorMetric {
+    /// Straight-line distance between RGB channels, in `[0, ~441.7]`.
+    #[default]
+    Euclidean,
+    /// The "redmean" approximation, weighting RGB channels by how sensitive the eye
+    /// is to each, in `[0, ~764.8]`. Much cheaper than the Lab-based metrics.
+    WeightedRgb,
+    /// CIE76 ΔE: straight-line distance in CIE Lab. A difference of about `2.3`
+    /// is just noticeable.
+    Cie76,
+    /// CIEDE2000 ΔE: the CIE's most perceptually uniform difference formula, correcting
+    /// CIE76 for hue, chroma and lightness. A difference of about `1` is just noticeable.
+    Ciede2000,
+    /// Straight-line distance in OKLab, in `[0, ~1]`. Nearly as uniform as CIEDE2000
+    /// at a fraction of the cost.
+    Oklab,
+}
+
+impl Color {
+    /// Measures the difference between this color and another under the given metric.
+    ///
+    /// Identical colors have a distance of `0`; the scale of larger values depends on
+    /// the metric. The alpha channel is ignored.
+    pub fn distance(self, other: Color, metric: ColorMetric) -> f32 {
+        match metric {
+            ColorMetric::Euclidean => {
+                let [dr, dg, db] = rgb_deltas(self, other);
+                (dr * dr + dg * dg + db * db).sqrt()
+            }
+            ColorMetric::WeightedRgb => {
+                let [dr, dg, db] = rgb_deltas(self, other);
+                let r_mean = (self.r() as f32 + other.r() as f32) / 2.0;
+                let wr = 2.0 + r_mean / 256.0;
+                let wb = 2.0 + (255.0 - r_mean) / 256.0;
+                (wr * dr * dr + 4.0 * dg * dg + wb * db * db).sqrt()
+            }
+            ColorMetric::Cie76 => self.to_lab().delta_e76(other.to_lab()),
+            ColorMetric::Ciede2000 => self.to_lab().delta_e2000(other.to_lab()),
+            ColorMetric::Oklab => {
+                let a = self.to_oklab();
+                let b = other.to_oklab();
+                let (dl, da, db) = (a.l - b.l, a.a - b.a, a.b - b.b);
+                (dl * dl + da * da + db * db).sqrt()
+            }
+        }
+    }
+}
+
+impl Lab {
+    /// The CIE76 color difference: Euclidean distance in Lab.
+    pub fn delta_e76(self, other: Lab) -> f32 {
+        let (dl, da, db) = (self.l - other.l, self.a - other.a, self.b - other.b);
+        (dl * dl + da * da + db * db).sqrt()
+    }
+
+    /// The CIEDE2000 color difference.
+    ///
+    /// Follows Sharma, Wu and Dalal, "The CIEDE2000 Color-Difference Formula:
+    /// Implementation Notes, Supplementary Test Data, and Mathematical Observations" (2005),
+    /// with unit weighting factors.
+    pub fn delta_e2000(self, other: Lab) -> f32 {
+        const POW25_7: f64 = 6_103_515_625.0; // 25^7
+
+        let (l1, a1, b1) = (self.l as f64, self.a as f64, self.b as f64);
+        let (l2, a2, b2) = (other.l as f64, other.a as f64, other.b as f64);
+
+        let c_bar = ((a1 * a1 + b1 * b1).sqrt() + (a2 * a2 + b2 * b2).sqrt()) / 2.0;
+        let c_bar7 = c_bar.powi(7);
+        let g = 0.5 * (1.0 - (c_bar7 / (c_bar7 + POW25_7)).sqrt());
+
+        let a1p = (1.0 + g) * a1;
+        let a2p = (1.0 + g) * a2;
+        let c1p = (a1p * a1p + b1 * b1).sqrt();
+        let c2p = (a2p * a2p + b2 * b2).sqrt();
+        let h1p = hue_degrees(b1, a1p);
+        let h2p = hue_degrees(b2, a2p);
+
+        let dl = l2 - l1;
+        let dc = c2p - c1p;
+        let dh = if c1p * c2p == 0.0 {
+            0.0
+        } else {
+            let diff = h2p - h1p;
+            if diff > 180.0 {
+                diff - 360.0
+            } else if diff < -180.0 {
+                diff + 360.0
+            } else {
+                diff
+            }
+        };
+        let dh_big = 2.0 * (c1p * c2p).sqrt() * (dh / 2.0).to_radians().sin();
+
+        let l_bar = (l1 + l2) / 2.0;
+        let c_bar_p = (c1p + c2p) / 2.0;
+        let h_bar = if c1p * c2p == 0.0 {
+            h1p + h2p
+        } else if (h1p - h2p).abs() <= 180.0 {
+            (h1p + h2p) / 2.0
+        } else if h1p + h2p < 360.0 {
+            (h1p + h2p + 360.0) / 2.0
+        } else {
+            (h1p + h2p - 360.0) / 2.0
+        };
+
+        let t = 1.0
+            - 0.17 * (h_bar - 30.0).to_radians().cos()
+            + 0.24 * (2.0 * h_bar).to_radians().cos()
+            + 0.32 * (3.0 * h_bar + 6.0).to_radians().cos()
+            - 0.20 * (4.0 * h_bar - 63.0).to_radians().cos();
+
+        let d_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+        let c_bar_p7 = c_bar_p.powi(7);
+        let r_c = 2.0 * (c_bar_p7 / (c_bar_p7 + POW25_7)).sqrt();
+        let l_50 = (l_bar - 50.0).powi(2);
+        let s_l = 1.0 + 0.015 * l_50 / (20.0 + l_50).sqrt();
+        let s_c = 1.0 + 0.045 * c_bar_p;
+        let s_h = 1.0 + 0.015 * c_bar_p * t;
+        let r_t = -(2.0 * d_theta).to_radians().sin() * r_c;
+
+        let (tl, tc, th) = (dl / s_l, dc / s_c, dh_big / s_h);
+        (tl * tl + tc * tc + th * th + r_t * tc * th).sqrt() as f32
+    }
+}
+
+/// Differences between the RGB channels of two colors.
+fn rgb_deltas(a: Color, b: Color) -> [f32; 3] {
+    [
+        a.r() as f32 - b.r() as f32,
+        a.g() as f32 - b.g() as f32,
+        a.b() as f32 - b.b() as f32,
+    ]
+}
+
+/// Hue angle in degrees in `[0, 360)`, `0` for achromatic colors.
+fn hue_degrees(b: f64, a: f64) -> f64 {
+    if a == 0.0 && b == 0.0 {
+        return 0.0;
+    }
+    b.atan2(a).to_degrees().rem_euclid(360.0)
+}
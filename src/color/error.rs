@@ -1,9 +1,22 @@
 use thiserror::Error;
 
+/// An error encountered while parsing a [`Color`](crate::Color) from text.
 #[derive(Debug, Error)]
 pub enum ColorParseError {
-    #[error("hex code must be 6 or 8 characters long, got {0}")]
+    #[error("color string is empty")]
+    Empty,
+    #[error("hex code must be 3, 4, 6 or 8 characters long, got {0}")]
     InvalidLength(usize),
     #[error("invalid hexadecimal value for {0}: {1}")]
     InvalidHex(&'static str, #[source] std::num::ParseIntError),
-}
\ No newline at end of file
+    #[error("unknown color name `{0}`")]
+    UnknownName(String),
+    #[error("unknown color function `{0}`, expected rgb, rgba, hsl or hsla")]
+    UnknownFunction(String),
+    #[error("malformed color function `{0}`")]
+    MalformedFunction(String),
+    #[error("{function}() takes 3 or 4 components, got {count}")]
+    InvalidComponentCount { function: &'static str, count: usize },
+    #[error("invalid color component `{0}`")]
+    InvalidComponent(String),
+}
@@ -1,9 +1,25 @@
 use serde::{Deserialize, Serialize, Serializer, Deserializer};
 
-use crate::color::error::ColorParseError;
-
+mod composite;
+mod deep;
+mod distance;
 mod error;
 mod from;
+mod named;
+mod palette;
+mod parse;
+#[cfg(feature = "rand")]
+mod random;
+mod space;
+mod stats;
+
+pub use composite::CompositeMode;
+pub use deep::{Color16, ColorF32};
+pub use distance::ColorMetric;
+pub use error::ColorParseError;
+pub use palette::Palette;
+pub use space::{Hsl, Hsv, Lab, LinearRgb, Oklab};
+pub use stats::{ChannelStats, ColorStats};
 
 /// Represents a color with RGBA components.
 #[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
@@ -14,12 +30,7 @@ impl Serialize for Color {
     where
         S: Serializer,
     {
-        let hex = if self.0[3] == 255 {
-            format!("#{:02X}{:02X}{:02X}", self.0[0], self.0[1], self.0[2])
-        } else {
-            format!("#{:02X}{:02X}{:02X}{:02X}", self.0[0], self.0[1], self.0[2], self.0[3])
-        };
-        serializer.serialize_str(&hex)
+        serializer.collect_str(self)
     }
 }
 
@@ -29,7 +40,7 @@ impl<'de> Deserialize<'de> for Color {
         D: Deserializer<'de>,
     {
         let s: String = Deserialize::deserialize(deserializer)?;
-        Color::hex(&s).map_err(serde::de::Error::custom)
+        s.parse().map_err(serde::de::Error::custom)
     }
 }
 
@@ -41,25 +52,34 @@ impl Color {
 
     /// Creates a `Color` from a hexadecimal color code.
     ///
-    /// Supports `#RRGGBB` and `#RRGGBBAA` formats. If alpha is omitted,
-    /// it defaults to 255 (fully opaque).
+    /// Supports `#RRGGBB` and `#RRGGBBAA` formats, as well as the `#RGB` and `#RGBA`
+    /// shorthands where each digit is doubled. If alpha is omitted, it defaults to 255
+    /// (fully opaque). The leading `#` is optional.
+    ///
+    /// To also accept CSS functional notation and named colors, parse with [`str::parse`].
     pub fn hex(hex_code: &str) -> Result<Self, ColorParseError> {
         let hex = hex_code.trim_start_matches('#');
 
-        if hex.len() != 6 && hex.len() != 8 {
-            return Err(ColorParseError::InvalidLength(hex.len()));
-        }
+        let digits = match hex.len() {
+            3 | 4 => 1,
+            6 | 8 => 2,
+            len => return Err(ColorParseError::InvalidLength(len)),
+        };
+
+        let channel = |idx: usize, name: &'static str| {
+            // `get` fails on non-ASCII input; parsing the whole code then reports the bad digit.
+            let digit = hex.get(idx * digits..(idx + 1) * digits).unwrap_or(hex);
+            u8::from_str_radix(digit, 16)
+                .map(|value| if digits == 1 { value * 17 } else { value })
+                .map_err(|e| ColorParseError::InvalidHex(name, e))
+        };
 
-        let r = u8::from_str_radix(&hex[0..2], 16)
-            .map_err(|e| ColorParseError::InvalidHex("R", e))?;
-        let g = u8::from_str_radix(&hex[2..4], 16)
-            .map_err(|e| ColorParseError::InvalidHex("G", e))?;
-        let b = u8::from_str_radix(&hex[4..6], 16)
-            .map_err(|e| ColorParseError::InvalidHex("B", e))?;
+        let r = channel(0, "R")?;
+        let g = channel(1, "G")?;
+        let b = channel(2, "B")?;
 
-        let a = if hex.len() == 8 {
-            u8::from_str_radix(&hex[6..8], 16)
-                .map_err(|e| ColorParseError::InvalidHex("A", e))?
+        let a = if hex.len() == 4 || hex.len() == 8 {
+            channel(3, "A")?
         } else {
             255
         };
@@ -72,6 +92,9 @@ impl Color {
     /// The `opacity` parameter controls the blend ratio: `0` returns `self` unchanged,
     /// `255` returns `other`, and values in between produce intermediate colors.
     /// All four RGBA channels are blended independently.
+    ///
+    /// This is a plain interpolation, not alpha compositing; use [`composite`](Color::composite)
+    /// to draw a translucent color over another.
     pub fn blend(&self, color: Color, opacity: u8) -> Self {
         let a = opacity as u16; // to avoid overflow
         let inv_a = 255 - a;
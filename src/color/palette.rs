@@ -0,0 +1,258 @@
+use rustc_hash::FxHashMap;
+
+use crate::Color;
+use crate::color::ColorMetric;
+
+/// A fixed list of colors, such as a map legend, with nearest-color lookup.
+///
+/// ## Overview
+///
+/// A `Palette` pairs its colors with the [`ColorMetric`] used to decide which entry is
+/// closest to an arbitrary color. Palettes can be written by hand, or extracted from
+/// the colors of a region with [`median_cut`](Palette::median_cut) or
+/// [`kmeans`](Palette::kmeans), typically over [`PixelSet::as_colors`](crate::PixelSet::as_colors).
+///
+/// ```rust
+/// use pixelset::Color;
+/// use pixelset::color::{ColorMetric, Palette};
+///
+/// let legend = Palette::new(vec![Color::new(0, 0, 255, 255), Color::new(0, 128, 0, 255)])
+///     .with_metric(ColorMetric::Ciede2000);
+///
+/// assert_eq!(legend.nearest(Color::new(20, 40, 230, 255)), Some(Color::new(0, 0, 255, 255)));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Palette {
+    colors: Vec<Color>,
+    metric: ColorMetric,
+}
+
+impl Palette {
+    /// Creates a palette from a list of colors, using the default metric.
+    pub fn new(colors: Vec<Color>) -> Self {
+        Self { colors, metric: ColorMetric::default() }
+    }
+
+    /// Returns the palette with a different metric for nearest-color lookup.
+    pub fn with_metric(self, metric: ColorMetric) -> Self {
+        Self { metric, ..self }
+    }
+
+    /// Get the palette colors.
+    pub fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+
+    /// Get the metric used for nearest-color lookup.
+    pub fn metric(&self) -> ColorMetric {
+        self.metric
+    }
+
+    /// Returns the number of colors in the palette.
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Returns `true` if the palette has no colors.
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// Returns the index of the palette color closest to `color`, or `None` if the
+    /// palette is empty. Ties resolve to the earliest entry.
+    pub fn nearest_index(&self, color: Color) -> Option<usize> {
+        self.colors
+            .iter()
+            .map(|&entry| color.distance(entry, self.metric))
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(idx, _)| idx)
+    }
+
+    /// Returns the palette color closest to `color`, or `None` if the palette is empty.
+    pub fn nearest(&self, color: Color) -> Option<Color> {
+        self.nearest_index(color).map(|idx| self.colors[idx])
+    }
+
+    /// Extracts a palette of at most `k` colors using median-cut quantization.
+    ///
+    /// The RGB color cube is recursively split at the median of its widest channel until
+    /// there are `k` boxes, and each box contributes its mean color. Fewer than `k` colors
+    /// are returned if the input has fewer distinct colors.
+    pub fn median_cut(colors: impl IntoIterator<Item = Color>, k: usize) -> Self {
+        Self::new(median_cut(histogram(colors), k))
+    }
+
+    /// Extracts a palette of at most `k` colors by k-means clustering in RGB space.
+    ///
+    /// Clusters are seeded from [`median_cut`](Palette::median_cut), which gives a stable,
+    /// well-spread starting point, then refined for up to `iterations` rounds or until the
+    /// assignment stops changing.
+    pub fn kmeans(colors: impl IntoIterator<Item = Color>, k: usize, iterations: usize) -> Self {
+        let histogram = histogram(colors);
+        let mut centers: Vec<[f64; 4]> = median_cut(histogram.clone(), k)
+            .into_iter()
+            .map(channels)
+            .collect();
+        if centers.is_empty() {
+            return Self::new(Vec::new());
+        }
+
+        let mut assignment = vec![usize::MAX; histogram.len()];
+
+        for _ in 0..iterations {
+            let mut changed = false;
+            for (slot, &(color, _)) in assignment.iter_mut().zip(&histogram) {
+                let nearest = nearest_center(&centers, channels(color));
+                changed |= *slot != nearest;
+                *slot = nearest;
+            }
+            if !changed {
+                break;
+            }
+
+            let mut sums = vec![([0.0; 4], 0.0); centers.len()];
+            for (&cluster, &(color, count)) in assignment.iter().zip(&histogram) {
+                let (sum, weight) = &mut sums[cluster];
+                for (total, value) in sum.iter_mut().zip(channels(color)) {
+                    *total += value * count as f64;
+                }
+                *weight += count as f64;
+            }
+
+            for (center, (sum, weight)) in centers.iter_mut().zip(sums) {
+                // Empty clusters keep their previous center.
+                if weight > 0.0 {
+                    *center = sum.map(|total| total / weight);
+                }
+            }
+        }
+
+        Self::new(
+            centers
+                .into_iter()
+                .map(|center| center.map(|c| c.round() as u8).into())
+                .collect(),
+        )
+    }
+}
+
+impl From<Vec<Color>> for Palette {
+    fn from(colors: Vec<Color>) -> Self {
+        Self::new(colors)
+    }
+}
+
+impl FromIterator<Color> for Palette {
+    fn from_iter<T: IntoIterator<Item = Color>>(iter: T) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+/// Distinct colors with their occurrence counts, in first-seen order.
+fn histogram(colors: impl IntoIterator<Item = Color>) -> Vec<(Color, u64)> {
+    let mut index = FxHashMap::default();
+    let mut entries: Vec<(Color, u64)> = Vec::new();
+
+    for color in colors {
+        let idx = *index.entry(color).or_insert_with(|| {
+            entries.push((color, 0));
+            entries.len() - 1
+        });
+        entries[idx].1 += 1;
+    }
+
+    entries
+}
+
+/// Median-cut centers for weighted colors.
+fn median_cut(histogram: Vec<(Color, u64)>, k: usize) -> Vec<Color> {
+    if histogram.is_empty() || k == 0 {
+        return Vec::new();
+    }
+
+    let mut boxes = vec![histogram];
+    while boxes.len() < k {
+        let widest = boxes
+            .iter()
+            .enumerate()
+            .filter(|(_, entries)| entries.len() > 1)
+            .map(|(idx, entries)| (idx, widest_channel(entries)))
+            .max_by_key(|&(_, (_, range))| range);
+
+        let Some((idx, (channel, _))) = widest else { break };
+
+        let mut entries = boxes.swap_remove(idx);
+        entries.sort_unstable_by_key(|(color, _)| channel_value(*color, channel));
+
+        let total: u64 = entries.iter().map(|&(_, count)| count).sum();
+        let mut seen = 0;
+        let split = entries
+            .iter()
+            .position(|&(_, count)| {
+                seen += count;
+                seen * 2 >= total
+            })
+            .unwrap_or(0)
+            .clamp(0, entries.len() - 2)
+            + 1;
+
+        let upper = entries.split_off(split);
+        boxes.push(entries);
+        boxes.push(upper);
+    }
+
+    boxes.iter().map(|entries| weighted_mean(entries)).collect()
+}
+
+/// The RGB channel with the largest value range, and that range.
+fn widest_channel(entries: &[(Color, u64)]) -> (usize, u8) {
+    (0..3)
+        .map(|channel| {
+            let values = entries.iter().map(|&(color, _)| channel_value(color, channel));
+            let min = values.clone().min().unwrap_or(0);
+            let max = values.max().unwrap_or(0);
+            (channel, max - min)
+        })
+        .max_by_key(|&(channel, range)| (range, std::cmp::Reverse(channel)))
+        .unwrap_or((0, 0))
+}
+
+fn channel_value(color: Color, channel: usize) -> u8 {
+    match channel {
+        0 => color.r(),
+        1 => color.g(),
+        _ => color.b(),
+    }
+}
+
+fn channels(color: Color) -> [f64; 4] {
+    [color.r(), color.g(), color.b(), color.a()].map(|c| c as f64)
+}
+
+/// Mean of the weighted colors, rounded to the nearest channel value.
+fn weighted_mean(entries: &[(Color, u64)]) -> Color {
+    let mut sum = [0.0; 4];
+    let mut weight = 0.0;
+    for &(color, count) in entries {
+        for (total, value) in sum.iter_mut().zip(channels(color)) {
+            *total += value * count as f64;
+        }
+        weight += count as f64;
+    }
+    sum.map(|total| (total / weight).round() as u8).into()
+}
+
+/// Index of the center closest to `point` in RGB, ignoring alpha.
+fn nearest_center(centers: &[[f64; 4]], point: [f64; 4]) -> usize {
+    let distance = |center: &[f64; 4]| {
+        (0..3).map(|i| (center[i] - point[i]).powi(2)).sum::<f64>()
+    };
+
+    centers
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| distance(a).total_cmp(&distance(b)))
+        .map(|(idx, _)| idx)
+        .unwrap_or(0)
+}
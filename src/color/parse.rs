@@ -0,0 +1,133 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Color;
+use crate::color::Hsl;
+use crate::color::error::ColorParseError;
+use crate::color::named;
+
+impl FromStr for Color {
+    type Err = ColorParseError;
+
+    /// Parses a color from any of the textual forms below, ignoring surrounding whitespace
+    /// and ASCII case:
+    ///
+    /// - hex codes: `#RGB`, `#RGBA`, `#RRGGBB` and `#RRGGBBAA` (the `#` is optional)
+    /// - CSS functional notation: `rgb()`, `rgba()`, `hsl()` and `hsla()`, with comma or
+    ///   space separated components and an optional `/ alpha`
+    /// - CSS named colors, such as `rebeccapurple` or `transparent`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if s.is_empty() {
+            return Err(ColorParseError::Empty);
+        }
+        if s.starts_with('#') {
+            return Color::hex(s);
+        }
+        if let Some(open) = s.find('(') {
+            return parse_function(s, open);
+        }
+        if let Some(color) = named::lookup(s) {
+            return Ok(color);
+        }
+        if s.bytes().all(|b| b.is_ascii_hexdigit()) && matches!(s.len(), 3 | 4 | 6 | 8) {
+            return Color::hex(s);
+        }
+
+        Err(ColorParseError::UnknownName(s.to_string()))
+    }
+}
+
+impl fmt::Display for Color {
+    /// Formats the color as `#RRGGBB`, or `#RRGGBBAA` when it is not fully opaque.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [r, g, b, a] = self.0;
+        if a == 255 {
+            write!(f, "#{:02X}{:02X}{:02X}", r, g, b)
+        } else {
+            write!(f, "#{:02X}{:02X}{:02X}{:02X}", r, g, b, a)
+        }
+    }
+}
+
+/// Parses `name(components)`, where `open` is the index of the opening parenthesis.
+fn parse_function(s: &str, open: usize) -> Result<Color, ColorParseError> {
+    let body = s[open + 1..]
+        .strip_suffix(')')
+        .ok_or_else(|| ColorParseError::MalformedFunction(s.to_string()))?;
+
+    let components: Vec<&str> = body
+        .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+        .filter(|component| !component.is_empty())
+        .collect();
+
+    let function = match s[..open].trim().to_ascii_lowercase().as_str() {
+        "rgb" | "rgba" => "rgb",
+        "hsl" | "hsla" => "hsl",
+        _ => return Err(ColorParseError::UnknownFunction(s[..open].trim().to_string())),
+    };
+
+    if !matches!(components.len(), 3 | 4) {
+        return Err(ColorParseError::InvalidComponentCount {
+            function,
+            count: components.len(),
+        });
+    }
+
+    let alpha = match components.get(3) {
+        Some(alpha) => unit_or_percent(alpha)?,
+        None => 1.0,
+    };
+
+    if function == "rgb" {
+        let channel = |component: &str| -> Result<u8, ColorParseError> {
+            let value = match component.strip_suffix('%') {
+                Some(percent) => number(percent, component)? * 2.55,
+                None => number(component, component)?,
+            };
+            Ok(value.clamp(0.0, 255.0).round() as u8)
+        };
+
+        Ok(Color::new(
+            channel(components[0])?,
+            channel(components[1])?,
+            channel(components[2])?,
+            (alpha * 255.0).round() as u8,
+        ))
+    } else {
+        let hue = components[0];
+        let h = number(hue.strip_suffix("deg").unwrap_or(hue), hue)?;
+
+        Ok(Hsl {
+            h,
+            s: percent(components[1])?,
+            l: percent(components[2])?,
+            alpha,
+        }
+        .into())
+    }
+}
+
+/// A percentage such as `50%` (or a bare `50`), as a fraction in `[0, 1]`.
+fn percent(component: &str) -> Result<f32, ColorParseError> {
+    let value = number(component.strip_suffix('%').unwrap_or(component), component)?;
+    Ok((value / 100.0).clamp(0.0, 1.0))
+}
+
+/// A fraction such as `0.5`, or a percentage such as `50%`, clamped to `[0, 1]`.
+fn unit_or_percent(component: &str) -> Result<f32, ColorParseError> {
+    let value = match component.strip_suffix('%') {
+        Some(percent) => number(percent, component)? / 100.0,
+        None => number(component, component)?,
+    };
+    Ok(value.clamp(0.0, 1.0))
+}
+
+/// Parses a finite number, reporting `component` on failure.
+fn number(text: &str, component: &str) -> Result<f32, ColorParseError> {
+    text.parse::<f32>()
+        .ok()
+        .filter(|value| value.is_finite())
+        .ok_or_else(|| ColorParseError::InvalidComponent(component.to_string()))
+}
@@ -0,0 +1,33 @@
+use rand::Rng;
+
+use crate::Color;
+use crate::color::Hsl;
+
+impl Color {
+    /// Generates a fully opaque color with uniformly random RGB channels.
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::new(rng.random(), rng.random(), rng.random(), 255)
+    }
+
+    /// Generates `count` fully opaque colors with maximally separated hues.
+    ///
+    /// Hues are spaced evenly around the color wheel, starting from a random offset,
+    /// at a fixed saturation and lightness. This makes the palette well suited to
+    /// labelling regions that must remain distinguishable from each other.
+    pub fn distinct_palette<R: Rng + ?Sized>(count: usize, rng: &mut R) -> Vec<Self> {
+        let offset = rng.random_range(0.0..360.0);
+        let step = 360.0 / count.max(1) as f32;
+
+        (0..count)
+            .map(|idx| {
+                Hsl {
+                    h: offset + step * idx as f32,
+                    s: 0.75,
+                    l: 0.5,
+                    alpha: 1.0,
+                }
+                .into()
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,342 @@
+use crate::Color;
+
+/// A color in the HSV (hue, saturation, value) cylindrical model.
+///
+/// Hue is in degrees in `[0, 360)`; saturation, value and alpha are in `[0, 1]`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Hsv {
+    /// Hue angle in degrees.
+    pub h: f32,
+    /// Saturation, from gray (`0`) to fully saturated (`1`).
+    pub s: f32,
+    /// Value, from black (`0`) to full brightness (`1`).
+    pub v: f32,
+    /// Opacity, from transparent (`0`) to opaque (`1`).
+    pub alpha: f32,
+}
+
+/// A color in the HSL (hue, saturation, lightness) cylindrical model.
+///
+/// Hue is in degrees in `[0, 360)`; saturation, lightness and alpha are in `[0, 1]`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Hsl {
+    /// Hue angle in degrees.
+    pub h: f32,
+    /// Saturation, from gray (`0`) to fully saturated (`1`).
+    pub s: f32,
+    /// Lightness, from black (`0`) through the pure hue (`0.5`) to white (`1`).
+    pub l: f32,
+    /// Opacity, from transparent (`0`) to opaque (`1`).
+    pub alpha: f32,
+}
+
+/// A color in linear-light sRGB, with the sRGB transfer function removed.
+///
+/// All channels are in `[0, 1]`. Arithmetic such as averaging or blending is
+/// physically meaningful in this space, unlike on gamma-encoded [`Color`] channels.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct LinearRgb {
+    /// Linear red intensity.
+    pub r: f32,
+    /// Linear green intensity.
+    pub g: f32,
+    /// Linear blue intensity.
+    pub b: f32,
+    /// Opacity, from transparent (`0`) to opaque (`1`).
+    pub alpha: f32,
+}
+
+/// A color in the CIE L\*a\*b\* space, relative to the D65 white point.
+///
+/// Lightness is in `[0, 100]`; `a` (green to red) and `b` (blue to yellow) are
+/// roughly in `[-128, 127]` for colors within the sRGB gamut.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Lab {
+    /// Perceptual lightness.
+    pub l: f32,
+    /// Green (negative) to red (positive) axis.
+    pub a: f32,
+    /// Blue (negative) to yellow (positive) axis.
+    pub b: f32,
+    /// Opacity, from transparent (`0`) to opaque (`1`).
+    pub alpha: f32,
+}
+
+/// A color in the OKLab perceptual space.
+///
+/// Lightness is in `[0, 1]`; `a` and `b` are roughly in `[-0.4, 0.4]`. OKLab has
+/// more uniform hue and lightness than CIE Lab, making it well suited to gradients
+/// and color adjustments.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Oklab {
+    /// Perceptual lightness.
+    pub l: f32,
+    /// Green (negative) to red (positive) axis.
+    pub a: f32,
+    /// Blue (negative) to yellow (positive) axis.
+    pub b: f32,
+    /// Opacity, from transparent (`0`) to opaque (`1`).
+    pub alpha: f32,
+}
+
+/// D65 reference white in XYZ.
+const WHITE: [f32; 3] = [0.950_47, 1.0, 1.088_83];
+
+/// CIE Lab threshold `(6/29)`.
+const DELTA: f32 = 6.0 / 29.0;
+
+impl Color {
+    /// Converts this color to HSV.
+    pub fn to_hsv(self) -> Hsv {
+        self.into()
+    }
+
+    /// Converts this color to HSL.
+    pub fn to_hsl(self) -> Hsl {
+        self.into()
+    }
+
+    /// Converts this color to linear-light sRGB.
+    pub fn to_linear(self) -> LinearRgb {
+        self.into()
+    }
+
+    /// Converts this color to CIE L\*a\*b\* (D65).
+    pub fn to_lab(self) -> Lab {
+        self.into()
+    }
+
+    /// Converts this color to OKLab.
+    pub fn to_oklab(self) -> Oklab {
+        self.into()
+    }
+}
+
+impl From<Color> for Hsv {
+    fn from(color: Color) -> Self {
+        let [r, g, b, alpha] = unit_channels(color);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let chroma = max - min;
+
+        Hsv {
+            h: hue(r, g, b, max, chroma),
+            s: if max == 0.0 { 0.0 } else { chroma / max },
+            v: max,
+            alpha,
+        }
+    }
+}
+
+impl From<Hsv> for Color {
+    fn from(hsv: Hsv) -> Self {
+        let s = hsv.s.clamp(0.0, 1.0);
+        let v = hsv.v.clamp(0.0, 1.0);
+        let chroma = v * s;
+        let [r, g, b] = hue_to_rgb(hsv.h, chroma, v - chroma);
+        from_unit([r, g, b, hsv.alpha])
+    }
+}
+
+impl From<Color> for Hsl {
+    fn from(color: Color) -> Self {
+        let [r, g, b, alpha] = unit_channels(color);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let chroma = max - min;
+        let l = (max + min) / 2.0;
+
+        let s = if chroma == 0.0 {
+            0.0
+        } else {
+            chroma / (1.0 - (2.0 * l - 1.0).abs())
+        };
+
+        Hsl { h: hue(r, g, b, max, chroma), s, l, alpha }
+    }
+}
+
+impl From<Hsl> for Color {
+    fn from(hsl: Hsl) -> Self {
+        let s = hsl.s.clamp(0.0, 1.0);
+        let l = hsl.l.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let [r, g, b] = hue_to_rgb(hsl.h, chroma, l - chroma / 2.0);
+        from_unit([r, g, b, hsl.alpha])
+    }
+}
+
+impl From<Color> for LinearRgb {
+    fn from(color: Color) -> Self {
+        let [r, g, b, alpha] = unit_channels(color);
+        LinearRgb {
+            r: srgb_to_linear(r),
+            g: srgb_to_linear(g),
+            b: srgb_to_linear(b),
+            alpha,
+        }
+    }
+}
+
+impl From<LinearRgb> for Color {
+    fn from(linear: LinearRgb) -> Self {
+        from_unit([
+            linear_to_srgb(linear.r),
+            linear_to_srgb(linear.g),
+            linear_to_srgb(linear.b),
+            linear.alpha,
+        ])
+    }
+}
+
+impl From<Color> for Lab {
+    fn from(color: Color) -> Self {
+        let LinearRgb { r, g, b, alpha } = color.into();
+
+        let x = 0.412_456_4 * r + 0.357_576_1 * g + 0.180_437_5 * b;
+        let y = 0.212_672_9 * r + 0.715_152_2 * g + 0.072_175 * b;
+        let z = 0.019_333_9 * r + 0.119_192 * g + 0.950_304_1 * b;
+
+        let fx = lab_f(x / WHITE[0]);
+        let fy = lab_f(y / WHITE[1]);
+        let fz = lab_f(z / WHITE[2]);
+
+        Lab {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+            alpha,
+        }
+    }
+}
+
+impl From<Lab> for Color {
+    fn from(lab: Lab) -> Self {
+        let fy = (lab.l + 16.0) / 116.0;
+        let fx = fy + lab.a / 500.0;
+        let fz = fy - lab.b / 200.0;
+
+        let x = lab_f_inv(fx) * WHITE[0];
+        let y = lab_f_inv(fy) * WHITE[1];
+        let z = lab_f_inv(fz) * WHITE[2];
+
+        LinearRgb {
+            r: 3.240_454_2 * x - 1.537_138_5 * y - 0.498_531_4 * z,
+            g: -0.969_266 * x + 1.876_010_8 * y + 0.041_556 * z,
+            b: 0.055_643_4 * x - 0.204_025_9 * y + 1.057_225_2 * z,
+            alpha: lab.alpha,
+        }
+        .into()
+    }
+}
+
+impl From<Color> for Oklab {
+    fn from(color: Color) -> Self {
+        let LinearRgb { r, g, b, alpha } = color.into();
+
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+        Oklab {
+            l: 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            a: 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            b: 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+            alpha,
+        }
+    }
+}
+
+impl From<Oklab> for Color {
+    fn from(oklab: Oklab) -> Self {
+        let l = oklab.l + 0.396_337_78 * oklab.a + 0.215_803_76 * oklab.b;
+        let m = oklab.l - 0.105_561_346 * oklab.a - 0.063_854_17 * oklab.b;
+        let s = oklab.l - 0.089_484_18 * oklab.a - 1.291_485_5 * oklab.b;
+
+        let (l, m, s) = (l * l * l, m * m * m, s * s * s);
+
+        LinearRgb {
+            r: 4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            g: -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            b: -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+            alpha: oklab.alpha,
+        }
+        .into()
+    }
+}
+
+/// RGBA channels scaled to `[0, 1]`.
+pub(super) fn unit_channels(color: Color) -> [f32; 4] {
+    [color.r(), color.g(), color.b(), color.a()].map(|c| c as f32 / 255.0)
+}
+
+/// Builds a color from `[0, 1]` channels, clamping out-of-gamut values.
+pub(super) fn from_unit(channels: [f32; 4]) -> Color {
+    channels.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8).into()
+}
+
+/// Hue in degrees shared by HSV and HSL, `0` for grays.
+fn hue(r: f32, g: f32, b: f32, max: f32, chroma: f32) -> f32 {
+    if chroma == 0.0 {
+        return 0.0;
+    }
+
+    let sector = if max == r {
+        ((g - b) / chroma).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / chroma + 2.0
+    } else {
+        (r - g) / chroma + 4.0
+    };
+
+    sector * 60.0
+}
+
+/// RGB for a hue with the given chroma, offset by `m` on every channel.
+fn hue_to_rgb(h: f32, chroma: f32, m: f32) -> [f32; 3] {
+    let sector = h.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (sector.rem_euclid(2.0) - 1.0).abs());
+
+    let (r, g, b) = match sector as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    [r + m, g + m, b + m]
+}
+
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.040_45 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+fn lab_f(t: f32) -> f32 {
+    if t > DELTA * DELTA * DELTA {
+        t.cbrt()
+    } else {
+        t / (3.0 * DELTA * DELTA) + 4.0 / 29.0
+    }
+}
+
+fn lab_f_inv(t: f32) -> f32 {
+    if t > DELTA {
+        t * t * t
+    } else {
+        3.0 * DELTA * DELTA * (t - 4.0 / 29.0)
+    }
+}
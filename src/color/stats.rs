@@ -0,0 +1,108 @@
+use crate::Color;
+
+/// Summary statistics for a single color channel over a region.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChannelStats {
+    /// Arithmetic mean of the channel values.
+    pub mean: f64,
+    /// Population variance of the channel values.
+    pub variance: f64,
+    /// Smallest channel value.
+    pub min: u8,
+    /// Largest channel value.
+    pub max: u8,
+    /// Lower median of the channel values.
+    pub median: u8,
+    /// Number of pixels with each channel value, indexed by value.
+    pub histogram: [u64; 256],
+}
+
+impl ChannelStats {
+    /// Builds the statistics from a non-empty histogram.
+    fn from_histogram(histogram: [u64; 256], count: u64) -> Self {
+        let values = || histogram.iter().enumerate().filter(|&(_, &n)| n > 0);
+
+        let mean = values().map(|(v, &n)| v as f64 * n as f64).sum::<f64>() / count as f64;
+        let variance = values()
+            .map(|(v, &n)| (v as f64 - mean).powi(2) * n as f64)
+            .sum::<f64>()
+            / count as f64;
+
+        let mut seen = 0;
+        let median = values()
+            .find(|&(_, &n)| {
+                seen += n;
+                seen * 2 >= count
+            })
+            .map_or(0, |(v, _)| v as u8);
+
+        Self {
+            mean,
+            variance,
+            min: values().next().map_or(0, |(v, _)| v as u8),
+            max: values().next_back().map_or(0, |(v, _)| v as u8),
+            median,
+            histogram,
+        }
+    }
+
+    /// Standard deviation of the channel values.
+    pub fn std_dev(&self) -> f64 {
+        self.variance.sqrt()
+    }
+}
+
+/// Per-channel color statistics over a region, from [`PixelSet::color_stats`].
+///
+/// [`PixelSet::color_stats`]: crate::PixelSet::color_stats
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColorStats {
+    /// Number of pixels sampled.
+    pub count: u64,
+    /// Red channel statistics.
+    pub r: ChannelStats,
+    /// Green channel statistics.
+    pub g: ChannelStats,
+    /// Blue channel statistics.
+    pub b: ChannelStats,
+    /// Alpha channel statistics.
+    pub a: ChannelStats,
+}
+
+impl ColorStats {
+    /// Builds statistics from RGBA histograms, or `None` if no pixels were counted.
+    pub(crate) fn from_histograms(histograms: [[u64; 256]; 4]) -> Option<Self> {
+        let count: u64 = histograms[0].iter().sum();
+        if count == 0 {
+            return None;
+        }
+
+        let [r, g, b, a] = histograms.map(|histogram| ChannelStats::from_histogram(histogram, count));
+        Some(Self { count, r, g, b, a })
+    }
+
+    /// The mean color, with each channel rounded to the nearest value.
+    pub fn mean(&self) -> Color {
+        self.channels().map(|c| c.mean.round() as u8).into()
+    }
+
+    /// The per-channel median color.
+    pub fn median(&self) -> Color {
+        self.channels().map(|c| c.median).into()
+    }
+
+    /// The per-channel minimum color.
+    pub fn min(&self) -> Color {
+        self.channels().map(|c| c.min).into()
+    }
+
+    /// The per-channel maximum color.
+    pub fn max(&self) -> Color {
+        self.channels().map(|c| c.max).into()
+    }
+
+    /// The channel statistics in RGBA order.
+    pub fn channels(&self) -> [&ChannelStats; 4] {
+        [&self.r, &self.g, &self.b, &self.a]
+    }
+}
@@ -0,0 +1,388 @@
+//! Multi-set archive container.
+//!
+//! ```text
+//! magic        4 bytes   b"PXSA"
+//! version      u16
+//! dict_len     u32
+//! dictionary   dict_len bytes, empty when no dictionary was trained
+//! entry_count  u32
+//! entries      entry_count x (name_len: u16, name: utf-8, offset: u64, length: u64)
+//! data         concatenated zstd frames, offsets relative to the end of the entries
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{Cursor, Error, ErrorKind, Read, Result as IoResult, Seek, SeekFrom, Write};
+
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use ::serde::de;
+use base64::Engine;
+
+use crate::PixelSet;
+use super::{codec, CompressionDictionary};
+
+const MAGIC: &[u8; 4] = b"PXSA";
+const VERSION: u16 = 1;
+
+/// Upper bound on the size of a trained shared dictionary.
+const DICTIONARY_SIZE: usize = 16 * 1024;
+
+/// A collection of named `PixelSet`s stored together in a single file.
+///
+/// ## Overview
+///
+/// Every entry is compressed independently with zstd, primed by a dictionary shared
+/// across the whole archive. Small, similar regions (e.g. the named areas of one map)
+/// compress far better together than as separate [`CompressedPixelSet`]s, since the
+/// dictionary absorbs their common structure.
+///
+/// Entries stay compressed in memory and are only decompressed by [`get`]. For
+/// archives too large to load, [`ArchiveReader`] reads the table of contents and
+/// seeks to individual entries on demand.
+///
+/// Serializes as raw bytes, or a base64 string in human-readable formats.
+///
+/// [`CompressedPixelSet`]: crate::CompressedPixelSet
+/// [`get`]: PixelSetArchive::get
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PixelSetArchive {
+    /// Shared zstd dictionary, if entries are compressed with one.
+    dictionary: Option<CompressionDictionary>,
+    /// Compressed entry payloads, keyed by name.
+    entries: BTreeMap<String, Vec<u8>>,
+}
+
+impl PixelSetArchive {
+    /// Creates an empty archive without a shared dictionary.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty archive whose entries are compressed with an existing dictionary.
+    pub fn with_dictionary(dictionary: CompressionDictionary) -> Self {
+        Self { dictionary: Some(dictionary), entries: BTreeMap::new() }
+    }
+
+    /// Builds an archive from named sets, training a shared dictionary over all of them.
+    ///
+    /// If the sets are too few or too small to train a dictionary, entries are
+    /// compressed without one.
+    pub fn from_sets<'a, N: Into<String>>(
+        sets: impl IntoIterator<Item = (N, &'a PixelSet)>,
+    ) -> IoResult<Self> {
+        let sets: Vec<(String, &PixelSet)> = sets
+            .into_iter()
+            .map(|(name, set)| (name.into(), set))
+            .collect();
+
+        let dictionary = CompressionDictionary::train(
+            sets.iter().map(|(_, set)| *set),
+            DICTIONARY_SIZE,
+        ).ok();
+
+        let mut archive = Self { dictionary, entries: BTreeMap::new() };
+        for (name, set) in sets {
+            archive.insert(name, set)?;
+        }
+
+        Ok(archive)
+    }
+
+    /// Compresses `set` with the archive's dictionary and stores it under `name`,
+    /// replacing any existing entry with that name.
+    pub fn insert(&mut self, name: impl Into<String>, set: &PixelSet) -> IoResult<()> {
+        let name = name.into();
+        check_name(&name)?;
+
+        let bytes = super::encode_runs(set);
+        let compressed = codec::compress_bytes_with_dictionary(&bytes, self.dictionary())?;
+        self.entries.insert(name, compressed);
+        Ok(())
+    }
+
+    /// Decompresses the entry stored under `name`.
+    ///
+    /// Returns `Ok(None)` if there is no such entry.
+    pub fn get(&self, name: &str) -> IoResult<Option<PixelSet>> {
+        self.entries
+            .get(name)
+            .map(|compressed| decompress_entry(compressed, self.dictionary()))
+            .transpose()
+    }
+
+    /// Removes the entry stored under `name`, returning `true` if it existed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.entries.remove(name).is_some()
+    }
+
+    /// Returns `true` if the archive has an entry named `name`.
+    pub fn contains(&self, name: &str) -> bool {
+        self.entries.contains_key(name)
+    }
+
+    /// Returns the entry names in sorted order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the archive has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the shared zstd dictionary, empty if entries are compressed without one.
+    pub fn dictionary(&self) -> &[u8] {
+        self.dictionary.as_ref().map_or(&[], |d| d.bytes())
+    }
+
+    /// Returns the shared dictionary with its id, if entries are compressed with one.
+    pub fn compression_dictionary(&self) -> Option<&CompressionDictionary> {
+        self.dictionary.as_ref()
+    }
+
+    /// Writes the archive in its binary file format.
+    pub fn write_to(&self, writer: &mut impl Write) -> IoResult<()> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&(self.dictionary().len() as u32).to_le_bytes())?;
+        writer.write_all(self.dictionary())?;
+        writer.write_all(&(self.entries.len() as u32).to_le_bytes())?;
+
+        let mut offset = 0u64;
+        for (name, compressed) in &self.entries {
+            writer.write_all(&(name.len() as u16).to_le_bytes())?;
+            writer.write_all(name.as_bytes())?;
+            writer.write_all(&offset.to_le_bytes())?;
+            writer.write_all(&(compressed.len() as u64).to_le_bytes())?;
+            offset += compressed.len() as u64;
+        }
+
+        for compressed in self.entries.values() {
+            writer.write_all(compressed)?;
+        }
+
+        Ok(())
+    }
+
+    /// Encodes the archive in its binary file format.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.write_to(&mut buf).expect("writing to a Vec cannot fail");
+        buf
+    }
+
+    /// Loads an archive from its binary file format.
+    ///
+    /// Entries are kept compressed until requested with [`get`](PixelSetArchive::get).
+    pub fn from_bytes(bytes: &[u8]) -> IoResult<Self> {
+        Self::from_reader(Cursor::new(bytes))
+    }
+
+    /// Loads an archive from a seekable reader.
+    ///
+    /// Entries are kept compressed until requested with [`get`](PixelSetArchive::get).
+    pub fn from_reader<R: Read + Seek>(reader: R) -> IoResult<Self> {
+        let mut reader = ArchiveReader::new(reader)?;
+
+        let mut entries = BTreeMap::new();
+        for i in 0..reader.toc.len() {
+            let compressed = reader.read_entry(i)?;
+            entries.insert(reader.toc[i].name.clone(), compressed);
+        }
+
+        let dictionary = (!reader.dictionary.is_empty())
+            .then(|| CompressionDictionary::from_bytes(reader.dictionary));
+
+        Ok(Self { dictionary, entries })
+    }
+}
+
+/// A table-of-contents entry pointing into the data section.
+#[derive(Clone, Debug)]
+struct TocEntry {
+    name: String,
+    offset: u64,
+    length: u64,
+}
+
+/// Reads individual entries from an archive without loading the whole file.
+///
+/// Only the header, dictionary and table of contents are read up front;
+/// [`get`](ArchiveReader::get) seeks to and decompresses a single entry.
+#[derive(Debug)]
+pub struct ArchiveReader<R> {
+    reader: R,
+    dictionary: Vec<u8>,
+    toc: Vec<TocEntry>,
+    data_start: u64,
+}
+
+impl<R: Read + Seek> ArchiveReader<R> {
+    /// Reads the archive header and table of contents from `reader`.
+    ///
+    /// Fails with [`ErrorKind::InvalidData`] if the table of contents lists the same
+    /// name twice.
+    pub fn new(mut reader: R) -> IoResult<Self> {
+        let mut magic = [0u8; 4];
+        read_exact(&mut reader, &mut magic)?;
+        if &magic != MAGIC {
+            return Err(Error::new(ErrorKind::InvalidData, "not a pixel set archive"));
+        }
+
+        let version = u16::from_le_bytes(read_array(&mut reader)?);
+        if version != VERSION {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("unsupported archive version {}", version),
+            ));
+        }
+
+        let dict_len = u32::from_le_bytes(read_array(&mut reader)?) as usize;
+        let mut dictionary = Vec::new();
+        (&mut reader).take(dict_len as u64).read_to_end(&mut dictionary)?;
+        if dictionary.len() != dict_len {
+            return Err(truncated());
+        }
+
+        let entry_count = u32::from_le_bytes(read_array(&mut reader)?) as usize;
+        let mut toc = Vec::with_capacity(entry_count.min(1 << 16));
+        let mut names = BTreeSet::new();
+        for _ in 0..entry_count {
+            let name_len = u16::from_le_bytes(read_array(&mut reader)?) as usize;
+            let mut name = vec![0u8; name_len];
+            read_exact(&mut reader, &mut name)?;
+            let name = String::from_utf8(name)
+                .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+            if !names.insert(name.clone()) {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("duplicate archive entry {:?}", name),
+                ));
+            }
+
+            let offset = u64::from_le_bytes(read_array(&mut reader)?);
+            let length = u64::from_le_bytes(read_array(&mut reader)?);
+            toc.push(TocEntry { name, offset, length });
+        }
+
+        let data_start = reader.stream_position()?;
+
+        Ok(Self { reader, dictionary, toc, data_start })
+    }
+
+    /// Returns the entry names in archive order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.toc.iter().map(|entry| entry.name.as_str())
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.toc.len()
+    }
+
+    /// Returns `true` if the archive has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.toc.is_empty()
+    }
+
+    /// Seeks to and decompresses the entry stored under `name`.
+    ///
+    /// Returns `Ok(None)` if there is no such entry.
+    pub fn get(&mut self, name: &str) -> IoResult<Option<PixelSet>> {
+        let Some(i) = self.toc.iter().position(|entry| entry.name == name) else {
+            return Ok(None);
+        };
+
+        let compressed = self.read_entry(i)?;
+        decompress_entry(&compressed, &self.dictionary).map(Some)
+    }
+
+    /// Consumes the reader, returning the underlying stream.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    fn read_entry(&mut self, i: usize) -> IoResult<Vec<u8>> {
+        let entry = &self.toc[i];
+        let start = self.data_start.checked_add(entry.offset).ok_or_else(|| {
+            Error::new(ErrorKind::InvalidData, "archive entry offset is out of range")
+        })?;
+        self.reader.seek(SeekFrom::Start(start))?;
+
+        let mut compressed = Vec::new();
+        (&mut self.reader).take(entry.length).read_to_end(&mut compressed)?;
+        if compressed.len() as u64 != entry.length {
+            return Err(truncated());
+        }
+
+        Ok(compressed)
+    }
+}
+
+impl Serialize for PixelSetArchive {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let bytes = self.to_bytes();
+        if serializer.is_human_readable() {
+            let encoded = base64::engine::general_purpose::STANDARD.encode(&bytes);
+            serializer.serialize_str(&encoded)
+        } else {
+            serializer.serialize_bytes(&bytes)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PixelSetArchive {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = if deserializer.is_human_readable() {
+            let encoded: String = Deserialize::deserialize(deserializer)?;
+            base64::engine::general_purpose::STANDARD.decode(encoded)
+                .map_err(de::Error::custom)?
+        } else {
+            Deserialize::deserialize(deserializer)?
+        };
+
+        PixelSetArchive::from_bytes(&bytes).map_err(de::Error::custom)
+    }
+}
+
+fn decompress_entry(compressed: &[u8], dictionary: &[u8]) -> IoResult<PixelSet> {
+    let bytes = codec::decompress_bytes_with_dictionary(compressed, dictionary)?;
+    super::decode_runs(&bytes)
+}
+
+fn check_name(name: &str) -> IoResult<()> {
+    if name.len() > u16::MAX as usize {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("entry name is {} bytes long, at most {} allowed", name.len(), u16::MAX),
+        ));
+    }
+    Ok(())
+}
+
+fn read_exact(reader: &mut impl Read, buf: &mut [u8]) -> IoResult<()> {
+    reader.read_exact(buf).map_err(|e| match e.kind() {
+        ErrorKind::UnexpectedEof => truncated(),
+        _ => e,
+    })
+}
+
+fn read_array<const N: usize>(reader: &mut impl Read) -> IoResult<[u8; N]> {
+    let mut buf = [0u8; N];
+    read_exact(reader, &mut buf)?;
+    Ok(buf)
+}
+
+fn truncated() -> Error {
+    Error::new(ErrorKind::InvalidData, "archive data is truncated")
+}
@@ -1,9 +1,14 @@
-use std::io::{Error, ErrorKind, Result as IoResult};
+use std::io::{BufReader, Error, ErrorKind, Read, Result as IoResult, Write};
 use zstd::{encode_all, decode_all};
+use zstd::stream::{Decoder, Encoder};
+use zstd::dict::from_samples;
+
+/// The zstd compression level used for all pixel set payloads.
+const LEVEL: i32 = 18;
 
 /// Compress bytes using zstd compression.
 pub fn compress_bytes(uncompressed: &[u8]) -> IoResult<Vec<u8>> {
-    encode_all(uncompressed, 18)
+    encode_all(uncompressed, LEVEL)
         .map_err(Error::other)
 }
 
@@ -12,3 +17,48 @@ pub fn decompress_bytes(compressed: &[u8]) -> IoResult<Vec<u8>> {
     decode_all(compressed)
         .map_err(|e| Error::new(ErrorKind::InvalidData, e))
 }
+
+/// Create a streaming zstd encoder writing compressed output to `writer`.
+///
+/// The encoder must be finished with [`Encoder::finish`] to flush the frame epilogue.
+pub fn encoder<W: Write>(writer: W) -> IoResult<Encoder<'static, W>> {
+    Encoder::new(writer, LEVEL)
+}
+
+/// Create a streaming zstd decoder reading compressed input from `reader`.
+pub fn decoder<R: Read>(reader: R) -> IoResult<Decoder<'static, BufReader<R>>> {
+    Decoder::new(reader)
+}
+
+/// Compress bytes using zstd compression primed with a shared dictionary.
+///
+/// An empty dictionary compresses without one.
+pub fn compress_bytes_with_dictionary(uncompressed: &[u8], dictionary: &[u8]) -> IoResult<Vec<u8>> {
+    if dictionary.is_empty() {
+        return compress_bytes(uncompressed);
+    }
+
+    let mut encoder = Encoder::with_dictionary(Vec::new(), LEVEL, dictionary)?;
+    encoder.write_all(uncompressed)?;
+    encoder.finish()
+}
+
+/// Decompress bytes that were compressed with zstd and the given dictionary.
+///
+/// An empty dictionary decompresses without one.
+pub fn decompress_bytes_with_dictionary(compressed: &[u8], dictionary: &[u8]) -> IoResult<Vec<u8>> {
+    if dictionary.is_empty() {
+        return decompress_bytes(compressed);
+    }
+
+    let mut decoder = Decoder::with_dictionary(compressed, dictionary)?;
+    let mut buf = Vec::new();
+    decoder.read_to_end(&mut buf)
+        .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+    Ok(buf)
+}
+
+/// Train a zstd dictionary of at most `max_size` bytes from uncompressed samples.
+pub fn train_dictionary(samples: &[Vec<u8>], max_size: usize) -> IoResult<Vec<u8>> {
+    from_samples(samples, max_size)
+}
@@ -0,0 +1,114 @@
+use std::io::{Error, ErrorKind, Result as IoResult};
+
+use crate::PixelSet;
+use super::codec;
+
+/// Magic bytes identifying a payload compressed with a dictionary.
+pub(crate) const MAGIC: &[u8; 4] = b"PXSD";
+
+const HEADER_SIZE: usize = 4 + 4;
+
+/// A zstd dictionary trained on a corpus of `PixelSet`s.
+///
+/// ## Overview
+///
+/// Small sets pay a fixed zstd framing and warm-up cost that can outweigh the payload
+/// itself. A dictionary trained on representative sets primes the compressor with their
+/// common structure, so each set compresses to a fraction of its standalone size.
+///
+/// Every dictionary has an `id`, taken from the zstd dictionary header when present and
+/// otherwise derived from its contents. [`PixelSet::compress_with_dictionary`] records
+/// the id in the compressed header, and decompressing with a different dictionary fails
+/// instead of producing garbage.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompressionDictionary {
+    bytes: Vec<u8>,
+    id: u32,
+}
+
+impl CompressionDictionary {
+    /// Trains a dictionary of at most `max_size` bytes from the given sets.
+    ///
+    /// Training needs a reasonably large and varied corpus; it fails with an error
+    /// if the samples are too few or too small.
+    pub fn train<'a>(
+        sets: impl IntoIterator<Item = &'a PixelSet>,
+        max_size: usize,
+    ) -> IoResult<Self> {
+        let samples: Vec<Vec<u8>> = sets.into_iter().map(super::encode_runs).collect();
+        let bytes = codec::train_dictionary(&samples, max_size)?;
+        Ok(Self::from_bytes(bytes))
+    }
+
+    /// Wraps existing dictionary bytes, such as a previously saved trained dictionary.
+    pub fn from_bytes(bytes: Vec<u8>) -> Self {
+        let id = dictionary_id(&bytes);
+        Self { bytes, id }
+    }
+
+    /// Returns the identifier recorded in payloads compressed with this dictionary.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Get the raw dictionary bytes.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Consume self and return the raw dictionary bytes.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+/// Compress a PixelSet with a dictionary, prefixing the dictionary id.
+pub(crate) fn compress(pixel_set: &PixelSet, dictionary: &CompressionDictionary) -> IoResult<Vec<u8>> {
+    let compressed = codec::compress_bytes_with_dictionary(
+        &super::encode_runs(pixel_set),
+        &dictionary.bytes,
+    )?;
+
+    let mut buf = Vec::with_capacity(HEADER_SIZE + compressed.len());
+    buf.extend_from_slice(MAGIC);
+    buf.extend_from_slice(&dictionary.id.to_le_bytes());
+    buf.extend_from_slice(&compressed);
+    Ok(buf)
+}
+
+/// Read the dictionary id from a dictionary-compressed payload.
+pub(crate) fn payload_id(bytes: &[u8]) -> IoResult<u32> {
+    if bytes.len() < HEADER_SIZE || &bytes[0..4] != MAGIC {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "insufficient data for dictionary header",
+        ));
+    }
+
+    Ok(u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]))
+}
+
+/// Decompress a dictionary-compressed payload, checking the dictionary id first.
+pub(crate) fn decompress(bytes: &[u8], dictionary: &CompressionDictionary) -> IoResult<PixelSet> {
+    let id = payload_id(bytes)?;
+    if id != dictionary.id {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("dictionary mismatch: payload needs {}, got {}", id, dictionary.id),
+        ));
+    }
+
+    let buf = codec::decompress_bytes_with_dictionary(&bytes[HEADER_SIZE..], &dictionary.bytes)?;
+    super::decode_runs(&buf)
+}
+
+/// The zstd dictionary id, or an FNV-1a hash for raw-content dictionaries without one.
+fn dictionary_id(bytes: &[u8]) -> u32 {
+    if let Some(id) = zstd::zstd_safe::get_dict_id_from_dict(bytes) {
+        return id.get();
+    }
+
+    bytes.iter().fold(0x811c_9dc5u32, |hash, &byte| {
+        (hash ^ byte as u32).wrapping_mul(0x0100_0193)
+    })
+}
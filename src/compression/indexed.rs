@@ -0,0 +1,237 @@
+//! Row-indexed compressed layout.
+//!
+//! The set is split into horizontal bands of `rows_per_band` rows, and every
+//! non-empty band is compressed independently in the plain RLE format. A small
+//! uncompressed header records the total pixel count, the bounding box and an
+//! index of band offsets, so queries only decode the bands they touch.
+//!
+//! ```text
+//! magic          4 bytes   b"PXRI"
+//! rows_per_band  u16
+//! band_count     u32
+//! pixel_count    u64
+//! bounds         4 x u16   (min_x, min_y, max_x, max_y), zero when empty
+//! index          band_count x (first_y: u16, offset: u32, length: u32)
+//! data           concatenated zstd frames, offsets relative to the end of the index
+//! ```
+
+use std::io::{Error, ErrorKind, Result as IoResult};
+use std::ops::{Bound, RangeBounds};
+
+use crate::PixelSet;
+use crate::set::Run;
+
+/// Magic bytes identifying the row-indexed layout.
+pub(crate) const MAGIC: &[u8; 4] = b"PXRI";
+
+const HEADER_SIZE: usize = 4 + 2 + 4 + 8 + 8;
+const ENTRY_SIZE: usize = 2 + 4 + 4;
+
+/// A single entry in the band index.
+#[derive(Clone, Copy, Debug)]
+struct Band {
+    first_y: u16,
+    offset: usize,
+    length: usize,
+}
+
+/// The parsed header of a row-indexed payload, borrowing the band data.
+pub(crate) struct Indexed<'a> {
+    rows_per_band: u16,
+    pixel_count: u64,
+    bounds: Option<(u16, u16, u16, u16)>,
+    bands: Vec<Band>,
+    data: &'a [u8],
+}
+
+/// Compress a PixelSet into the row-indexed layout.
+pub(crate) fn compress(pixel_set: &PixelSet, rows_per_band: u16) -> IoResult<Vec<u8>> {
+    if rows_per_band == 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "rows per band must be at least 1",
+        ));
+    }
+
+    let runs = pixel_set.runs();
+
+    let mut index = Vec::new();
+    let mut data = Vec::new();
+
+    let mut start = 0;
+    while start < runs.len() {
+        let band_no = runs[start].y / rows_per_band;
+        let end = start + runs[start..].partition_point(|r| r.y / rows_per_band == band_no);
+
+        let band = PixelSet::from_runs_unchecked(runs[start..end].to_vec());
+        let compressed = super::compress_to_bytes(&band)?;
+
+        index.extend_from_slice(&(band_no * rows_per_band).to_le_bytes());
+        index.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        index.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+        data.extend_from_slice(&compressed);
+
+        start = end;
+    }
+
+    let (min_x, min_y, max_x, max_y) = pixel_set.bounds().unwrap_or((0, 0, 0, 0));
+    let band_count = index.len() / ENTRY_SIZE;
+
+    let mut buf = Vec::with_capacity(HEADER_SIZE + index.len() + data.len());
+    buf.extend_from_slice(MAGIC);
+    buf.extend_from_slice(&rows_per_band.to_le_bytes());
+    buf.extend_from_slice(&(band_count as u32).to_le_bytes());
+    buf.extend_from_slice(&(pixel_set.len() as u64).to_le_bytes());
+    for value in [min_x, min_y, max_x, max_y] {
+        buf.extend_from_slice(&value.to_le_bytes());
+    }
+    buf.extend_from_slice(&index);
+    buf.extend_from_slice(&data);
+
+    Ok(buf)
+}
+
+impl<'a> Indexed<'a> {
+    /// Parse the header and band index of a row-indexed payload.
+    pub(crate) fn parse(bytes: &'a [u8]) -> IoResult<Self> {
+        if bytes.len() < HEADER_SIZE || &bytes[0..4] != MAGIC {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "insufficient data for row-indexed header",
+            ));
+        }
+
+        let u16_at = |off: usize| u16::from_le_bytes([bytes[off], bytes[off + 1]]);
+        let u32_at = |off: usize| {
+            u32::from_le_bytes([bytes[off], bytes[off + 1], bytes[off + 2], bytes[off + 3]])
+        };
+
+        let rows_per_band = u16_at(4);
+        let band_count = u32_at(6) as usize;
+        let pixel_count = u64::from_le_bytes(bytes[10..18].try_into().unwrap());
+        let bounds = (pixel_count > 0)
+            .then(|| (u16_at(18), u16_at(20), u16_at(22), u16_at(24)));
+
+        let data_start = HEADER_SIZE + band_count * ENTRY_SIZE;
+        if rows_per_band == 0 || bytes.len() < data_start {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("malformed band index: expected {} bands", band_count),
+            ));
+        }
+
+        let data = &bytes[data_start..];
+        let mut bands: Vec<Band> = Vec::with_capacity(band_count);
+        for i in 0..band_count {
+            let off = HEADER_SIZE + i * ENTRY_SIZE;
+            let band = Band {
+                first_y: u16_at(off),
+                offset: u32_at(off + 2) as usize,
+                length: u32_at(off + 6) as usize,
+            };
+
+            if band.offset + band.length > data.len() {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("band {} extends past the end of the data", i),
+                ));
+            }
+
+            if let Some(prev) = bands.last()
+                && prev.first_y as u32 + rows_per_band as u32 > band.first_y as u32
+            {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("band {} at row {} overlaps the band before it", i, band.first_y),
+                ));
+            }
+
+            bands.push(band);
+        }
+
+        Ok(Self { rows_per_band, pixel_count, bounds, bands, data })
+    }
+
+    /// Total number of pixels, read from the header.
+    pub(crate) fn pixel_count(&self) -> usize {
+        self.pixel_count as usize
+    }
+
+    /// Bounding box, read from the header.
+    pub(crate) fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        self.bounds
+    }
+
+    /// Check membership by decoding only the band containing `y`.
+    pub(crate) fn has(&self, x: u16, y: u16) -> IoResult<bool> {
+        let idx = self.bands.partition_point(|b| b.first_y <= y);
+        if idx == 0 {
+            return Ok(false);
+        }
+
+        let band = self.bands[idx - 1];
+        if (y - band.first_y) >= self.rows_per_band {
+            return Ok(false);
+        }
+
+        Ok(self.decode(band)?.has(crate::Pixel::new(x, y)))
+    }
+
+    /// Decode the rows within `range`, touching only the overlapping bands.
+    pub(crate) fn rows(&self, range: impl RangeBounds<u16>) -> IoResult<PixelSet> {
+        let lo = match range.start_bound() {
+            Bound::Included(&y) => y as u32,
+            Bound::Excluded(&y) => y as u32 + 1,
+            Bound::Unbounded => 0,
+        };
+        let hi = match range.end_bound() {
+            Bound::Included(&y) => y as u32 + 1,
+            Bound::Excluded(&y) => y as u32,
+            Bound::Unbounded => u16::MAX as u32 + 1,
+        };
+
+        let mut runs: Vec<Run> = Vec::new();
+        for &band in &self.bands {
+            let band_end = band.first_y as u32 + self.rows_per_band as u32;
+            if band_end <= lo || band.first_y as u32 >= hi {
+                continue;
+            }
+
+            let set = self.decode(band)?;
+            runs.extend(
+                set.runs()
+                    .iter()
+                    .filter(|r| (lo..hi).contains(&(r.y as u32))),
+            );
+        }
+
+        Ok(PixelSet::from_runs_unchecked(runs))
+    }
+
+    /// Decode every band into a single PixelSet.
+    pub(crate) fn decompress(&self) -> IoResult<PixelSet> {
+        self.rows(..)
+    }
+
+    /// Decode a single band, checking that its runs are valid and lie within its rows.
+    fn decode(&self, band: Band) -> IoResult<PixelSet> {
+        let set = super::decompress_from_bytes(&self.data[band.offset..band.offset + band.length])?;
+
+        if let Err(reason) = set.validate_invariants() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("malformed band at row {}: {}", band.first_y, reason),
+            ));
+        }
+
+        let band_end = band.first_y as u32 + self.rows_per_band as u32;
+        if let Some(run) = set.runs().iter().find(|r| r.y < band.first_y || r.y as u32 >= band_end) {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("band at row {} holds a run on row {}", band.first_y, run.y),
+            ));
+        }
+
+        Ok(set)
+    }
+}
@@ -1,9 +1,17 @@
+mod archive;
 mod codec;
+mod dictionary;
+mod indexed;
 pub(crate) mod serde;
 
-use std::io::{Error, ErrorKind, Result as IoResult};
-use crate::{PixelSet};
+pub use archive::{ArchiveReader, PixelSetArchive};
+pub use dictionary::CompressionDictionary;
+
+use std::io::{Error, ErrorKind, Read, Result as IoResult, Write};
+use std::ops::RangeBounds;
+use crate::{Pixel, PixelSet};
 use crate::set::Run;
+use indexed::Indexed;
 
 use ::serde::{Serialize, Deserialize, Serializer, Deserializer};
 use ::serde::de;
@@ -13,6 +21,24 @@ use base64::Engine;
 ///
 /// This type wraps compressed bytes that can be decompressed back to a PixelSet.
 /// Achieves 75-150x compression on regular geographic data.
+///
+/// ## Layouts
+///
+/// - **Plain** ([`PixelSet::compress`]): the whole set as a single zstd frame.
+///   Every query decompresses the full set.
+/// - **Row-indexed** ([`PixelSet::compress_indexed`]): the set is split into
+///   independently compressed bands of rows behind a small index. [`has`],
+///   [`bounds`], [`pixel_count`] and [`rows`] only decode the bands they need,
+///   and the header answers `bounds` and `pixel_count` without decoding at all.
+/// - **Dictionary** ([`PixelSet::compress_with_dictionary`]): a single zstd frame
+///   primed with a [`CompressionDictionary`], prefixed by the dictionary id. Must be
+///   read back with [`decompress_with_dictionary`] using the same dictionary.
+///
+/// [`has`]: CompressedPixelSet::has
+/// [`bounds`]: CompressedPixelSet::bounds
+/// [`pixel_count`]: CompressedPixelSet::pixel_count
+/// [`rows`]: CompressedPixelSet::rows
+/// [`decompress_with_dictionary`]: CompressedPixelSet::decompress_with_dictionary
 #[derive(Clone, Debug)]
 pub struct CompressedPixelSet {
     bytes: Vec<u8>,
@@ -76,31 +102,143 @@ impl CompressedPixelSet {
     }
 
     /// Decompress this CompressedPixelSet back into a PixelSet.
+    ///
+    /// Fails with an error of kind `InvalidInput` if the set was compressed with a
+    /// dictionary; use [`decompress_with_dictionary`](CompressedPixelSet::decompress_with_dictionary).
     pub fn decompress(&self) -> IoResult<PixelSet> {
-        decompress_from_bytes(&self.bytes)
+        if let Some(id) = self.dictionary_id() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("compressed with dictionary {}, which must be provided", id),
+            ));
+        }
+
+        match self.indexed()? {
+            Some(indexed) => indexed.decompress(),
+            None => decompress_from_bytes(&self.bytes),
+        }
     }
-}
 
-/// Compress a PixelSet to compressed bytes using RLE binary format and zstd.
-pub(crate) fn compress_to_bytes(pixel_set: &PixelSet) -> IoResult<Vec<u8>> {
-    let runs = pixel_set.runs();
+    /// Decompress a CompressedPixelSet that was compressed with a dictionary.
+    ///
+    /// Fails with an error of kind `InvalidInput` if `dictionary` is not the one the
+    /// set was compressed with. Sets compressed without a dictionary are decompressed
+    /// normally.
+    pub fn decompress_with_dictionary(&self, dictionary: &CompressionDictionary) -> IoResult<PixelSet> {
+        if self.dictionary_id().is_none() {
+            return self.decompress();
+        }
 
-    let mut buf = Vec::with_capacity(4 + runs.len() * 6);
-    buf.extend_from_slice(&(runs.len() as u32).to_le_bytes());
+        dictionary::decompress(&self.bytes, dictionary)
+    }
 
-    for run in runs {
-        buf.extend_from_slice(&run.y.to_le_bytes());
-        buf.extend_from_slice(&run.x_start.to_le_bytes());
-        buf.extend_from_slice(&run.length.to_le_bytes());
+    /// Returns the id of the dictionary this set was compressed with, if any.
+    pub fn dictionary_id(&self) -> Option<u32> {
+        if self.bytes.starts_with(dictionary::MAGIC) {
+            dictionary::payload_id(&self.bytes).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the compressed set contains the specified pixel.
+    ///
+    /// With the row-indexed layout only the band containing the pixel's row
+    /// is decompressed.
+    pub fn has(&self, pixel: Pixel) -> IoResult<bool> {
+        match self.indexed()? {
+            Some(indexed) => indexed.has(pixel.x, pixel.y),
+            None => Ok(self.decompress()?.has(pixel)),
+        }
+    }
+
+    /// Returns the bounding box of the compressed set as (min_x, min_y, max_x, max_y).
+    ///
+    /// With the row-indexed layout this is read from the header without decompressing.
+    pub fn bounds(&self) -> IoResult<Option<(u16, u16, u16, u16)>> {
+        match self.indexed()? {
+            Some(indexed) => Ok(indexed.bounds()),
+            None => Ok(self.decompress()?.bounds()),
+        }
+    }
+
+    /// Returns the number of pixels in the compressed set.
+    ///
+    /// With the row-indexed layout this is read from the header without decompressing.
+    /// Not to be confused with [`len`](CompressedPixelSet::len), which is the compressed size.
+    pub fn pixel_count(&self) -> IoResult<usize> {
+        match self.indexed()? {
+            Some(indexed) => Ok(indexed.pixel_count()),
+            None => Ok(self.decompress()?.len()),
+        }
+    }
+
+    /// Decompresses only the pixels whose row lies within `range`.
+    ///
+    /// With the row-indexed layout only the overlapping bands are decompressed.
+    pub fn rows(&self, range: impl RangeBounds<u16>) -> IoResult<PixelSet> {
+        match self.indexed()? {
+            Some(indexed) => indexed.rows(range),
+            None => {
+                let set = self.decompress()?;
+                let runs = set.runs()
+                    .iter()
+                    .filter(|r| range.contains(&r.y))
+                    .copied()
+                    .collect();
+                Ok(PixelSet::from_runs_unchecked(runs))
+            }
+        }
+    }
+
+    /// Parse the row-indexed header, or `None` for the plain layout.
+    fn indexed(&self) -> IoResult<Option<Indexed<'_>>> {
+        if self.bytes.starts_with(indexed::MAGIC) {
+            Indexed::parse(&self.bytes).map(Some)
+        } else {
+            Ok(None)
+        }
     }
+}
+
+/// Compress a PixelSet with a trained dictionary, recording the dictionary id.
+pub(crate) fn compress_with_dictionary_to_bytes(
+    pixel_set: &PixelSet,
+    dictionary: &CompressionDictionary,
+) -> IoResult<Vec<u8>> {
+    dictionary::compress(pixel_set, dictionary)
+}
+
+/// Compress a PixelSet into the row-indexed layout with bands of `rows_per_band` rows.
+pub(crate) fn compress_indexed_to_bytes(pixel_set: &PixelSet, rows_per_band: u16) -> IoResult<Vec<u8>> {
+    indexed::compress(pixel_set, rows_per_band)
+}
+
+/// Size in bytes of a single encoded run: `y`, `x_start` and `length` as little-endian u16s.
+const RUN_SIZE: usize = 6;
 
-    codec::compress_bytes(&buf)
+/// Compress a PixelSet to compressed bytes using RLE binary format and zstd.
+pub(crate) fn compress_to_bytes(pixel_set: &PixelSet) -> IoResult<Vec<u8>> {
+    codec::compress_bytes(&encode_runs(pixel_set))
 }
 
 /// Decompress bytes back to a PixelSet using RLE binary format and zstd.
 pub(crate) fn decompress_from_bytes(compressed: &[u8]) -> IoResult<PixelSet> {
     let buf = codec::decompress_bytes(compressed)?;
+    decode_runs(&buf)
+}
+
+/// Encode a PixelSet into the uncompressed RLE binary format.
+pub(crate) fn encode_runs(pixel_set: &PixelSet) -> Vec<u8> {
+    let runs = pixel_set.runs();
+
+    let mut buf = Vec::with_capacity(4 + runs.len() * RUN_SIZE);
+    write_runs(runs, &mut buf).expect("writing to a Vec cannot fail");
+    buf
+}
 
+/// Decode a PixelSet from the uncompressed RLE binary format.
+pub(crate) fn decode_runs(buf: &[u8]) -> IoResult<PixelSet> {
     if buf.len() < 4 {
         return Err(Error::new(
             ErrorKind::InvalidData,
@@ -110,7 +248,7 @@ pub(crate) fn decompress_from_bytes(compressed: &[u8]) -> IoResult<PixelSet> {
 
     let n_runs = u32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]) as usize;
 
-    let expected_len = 4 + n_runs * 6;
+    let expected_len = 4 + n_runs * RUN_SIZE;
     if buf.len() != expected_len {
         return Err(Error::new(
             ErrorKind::InvalidData,
@@ -122,14 +260,83 @@ pub(crate) fn decompress_from_bytes(compressed: &[u8]) -> IoResult<PixelSet> {
         ));
     }
 
-    let mut runs = Vec::with_capacity(n_runs);
+    let runs = buf[4..]
+        .chunks_exact(RUN_SIZE)
+        .map(decode_run)
+        .collect();
+
+    Ok(PixelSet::from_runs_unchecked(runs))
+}
+
+/// Stream a PixelSet through a zstd encoder into `writer`.
+///
+/// Produces the same format as [`compress_to_bytes`], without buffering the
+/// uncompressed payload or the compressed output in memory.
+pub(crate) fn compress_to_writer<W: Write>(pixel_set: &PixelSet, writer: W) -> IoResult<()> {
+    let mut encoder = codec::encoder(writer)?;
+    write_runs(pixel_set.runs(), &mut encoder)?;
+    encoder.finish()?;
+    Ok(())
+}
+
+/// Stream a PixelSet out of a zstd decoder reading from `reader`.
+///
+/// Accepts the same format as [`decompress_from_bytes`], decoding runs one at a time.
+pub(crate) fn decompress_from_reader<R: Read>(reader: R) -> IoResult<PixelSet> {
+    let mut decoder = codec::decoder(reader)?;
+
+    let mut header = [0u8; 4];
+    decoder.read_exact(&mut header).map_err(|e| match e.kind() {
+        ErrorKind::UnexpectedEof => Error::new(
+            ErrorKind::InvalidData,
+            "insufficient data for run count",
+        ),
+        _ => e,
+    })?;
+
+    let n_runs = u32::from_le_bytes(header) as usize;
+
+    // The run count comes from untrusted input, so cap the up-front allocation.
+    let mut runs = Vec::with_capacity(n_runs.min(1 << 16));
+    let mut run = [0u8; RUN_SIZE];
     for i in 0..n_runs {
-        let off = 4 + i * 6;
-        let y = u16::from_le_bytes([buf[off], buf[off + 1]]);
-        let x_start = u16::from_le_bytes([buf[off + 2], buf[off + 3]]);
-        let length = u16::from_le_bytes([buf[off + 4], buf[off + 5]]);
-        runs.push(Run { y, x_start, length });
+        decoder.read_exact(&mut run).map_err(|e| match e.kind() {
+            ErrorKind::UnexpectedEof => Error::new(
+                ErrorKind::InvalidData,
+                format!("malformed run data: expected {} runs, got {}", n_runs, i),
+            ),
+            _ => e,
+        })?;
+        runs.push(decode_run(&run));
+    }
+
+    if decoder.read(&mut run)? != 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("malformed run data: trailing bytes after {} runs", n_runs),
+        ));
     }
 
     Ok(PixelSet::from_runs_unchecked(runs))
 }
+
+/// Write the run count followed by every run in RLE binary format.
+fn write_runs(runs: &[Run], writer: &mut impl Write) -> IoResult<()> {
+    writer.write_all(&(runs.len() as u32).to_le_bytes())?;
+
+    for run in runs {
+        writer.write_all(&run.y.to_le_bytes())?;
+        writer.write_all(&run.x_start.to_le_bytes())?;
+        writer.write_all(&run.length.to_le_bytes())?;
+    }
+
+    Ok(())
+}
+
+/// Decode a single run from its `RUN_SIZE`-byte binary form.
+fn decode_run(bytes: &[u8]) -> Run {
+    let y = u16::from_le_bytes([bytes[0], bytes[1]]);
+    let x_start = u16::from_le_bytes([bytes[2], bytes[3]]);
+    let length = u16::from_le_bytes([bytes[4], bytes[5]]);
+    Run { y, x_start, length }
+}
@@ -133,7 +133,22 @@ impl Direction {
     /// assert!((east.dot(north) - 0.0).abs() < 1e-6); // Perpendicular
     /// ```
     pub fn dot(&self, other: Direction) -> f32 {
-        self.dx * other.dx + self.dy * other.dy
+        self.project(other.dx, other.dy)
+    }
+
+    /// Projects the vector `(x, y)` onto this direction.
+    ///
+    /// This is the dot product with an arbitrary vector rather than another direction:
+    /// the signed length of the vector's component along this direction.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let east = Direction::new(1.0, 0.0).unwrap();
+    /// assert!((east.project(3.0, 4.0) - 3.0).abs() < 1e-6);
+    /// ```
+    pub fn project(&self, x: f32, y: f32) -> f32 {
+        self.dx * x + self.dy * y
     }
 
     /// Compares the alignment of this direction with another, returning a score in `[-1, 1]`.
@@ -13,8 +13,12 @@
 //! - **[`Pixel`]**: Represents a single 2D coordinate with `x` and `y` fields. Implements custom
 //!   ordering and hashing that preserves the `(y, x)` sort order used throughout the library.
 //!
-//! - **[`Color`]**: A simple RGBA color representation with methods for parsing hex codes,
-//!   blending, grayscale conversion, and optional random color generation (with `rand` feature).
+//! - **[`Color`]**: A simple RGBA color representation that parses hex codes, CSS `rgb()`/`hsl()`
+//!   notation and named colors via [`str::parse`], with methods for blending, grayscale
+//!   conversion, and optional random color generation (with `rand` feature).
+//!   Converts to and from HSV, HSL, linear sRGB, CIE Lab and OKLab via the [`color`] module.
+//!   [`Color16`](color::Color16) and [`ColorF32`](color::ColorF32) read and write
+//!   16-bit and floating-point images without loss of precision.
 //!
 //! ## Working with Shapes
 //!
@@ -24,8 +28,22 @@
 //! - **[`RectangleOutline`]**: Rectangle borders with adjustable stroke width
 //! - **[`Ellipse`]**: Filled ellipses using the standard ellipse equation
 //! - **[`EllipseOutline`]**: Ellipse borders with adjustable stroke width
+//! - **[`Line`](shapes::Line)**: Straight segments, single-pixel or thick with round or square caps
+//! - **[`Polyline`](shapes::Polyline)**: Connected chains of line segments
+//! - **[`QuadraticBezier`](shapes::QuadraticBezier)** and **[`CubicBezier`](shapes::CubicBezier)**:
+//!   Smooth stroked curves, flattened adaptively
+//! - **[`Arc`](shapes::Arc)**: Part of an ellipse outline between two angles
+//! - **[`RotatedRectangle`](shapes::RotatedRectangle)** and **[`RotatedEllipse`](shapes::RotatedEllipse)**:
+//!   Filled shapes rotated about their center
+//! - **[`Triangle`](shapes::Triangle)**, **[`RegularPolygon`](shapes::RegularPolygon)**,
+//!   **[`Star`](shapes::Star)** and **[`RoundedRectangle`](shapes::RoundedRectangle)**: Icon
+//!   primitives, each with a matching outline variant
 //!
 //! All shapes implement the [`Shape`] trait, allowing generic code that works with any shape.
+//! Custom shapes only need to provide [`Shape::bounds`] and [`Shape::row_spans`] to be
+//! rasterized run by run.
+//! Shapes compose lazily with [`Shape::union`], [`Shape::intersection`],
+//! [`Shape::difference`], [`Shape::translate`] and [`Shape::clip_to`].
 //!
 //! ## Common Operations
 //!
@@ -42,13 +60,37 @@
 //! let outline = pixels.outline(&image);           // boundary pixels
 //! let neighbors = pixels.neighbors(&image);       // adjacent pixels
 //! let touching = pixels.touching(&other, &image); // pixels adjacent to another set
+//! let hit = pixels.raycast(origin, dir, 100.0);  // first pixel along a ray
+//! let sight = pixels.line_of_sight(a, b);         // does a segment stay inside?
+//! let shadow = pixels.sweep(dir, 8.0);            // extrude along a direction
+//! let extent = pixels.extent_along(dir);          // length and width along it
 //! ```
 //!
 //! **Color Operations**:
 //! ```ignore
 //! pixels.fill(&mut image, Color::WHITE);           // solid color
+//! pixels.fill_with(&mut image, Paint::RadialGradient { from, to }); // gradients, patterns
+//! pixels.fill_antialiased(&mut image, &circle, Color::BLACK); // soft shape edges
 //! pixels.transform(&mut image, |c| c.grayscale()); // apply transformation
+//! pixels.transform(&mut image, |c| {               // adjust hue in HSV
+//!     let mut hsv = c.to_hsv();
+//!     hsv.h += 30.0;
+//!     hsv.into()
+//! });
 //! let avg = pixels.mean_color(&image);             // average color
+//! let stats = pixels.color_stats(&image);         // mean, variance, median, histogram
+//! pixels.copy_to(&image, &mut canvas, (10, -5));   // blit the region elsewhere
+//! let sprite = pixels.extract(&image);            // crop to a transparent RGBA image
+//! let near = pixels.select_similar(&image, color, ColorMetric::Ciede2000, 2.0); // fuzzy match
+//! let palette = Palette::median_cut(pixels.as_colors(&image), 8); // extract palette
+//! pixels.quantize(&mut image, &palette);          // snap to nearest palette color
+//! ```
+//!
+//! **Serialization**: `PixelSet` serializes as compact compressed runs by default. The
+//! [`serde`](mod@serde) module provides readable alternatives for config files:
+//! ```ignore
+//! #[serde(with = "pixelset::serde::rects")]
+//! area: PixelSet,
 //! ```
 //!
 //! ## Design Philosophy
@@ -86,11 +128,13 @@ mod set;
 pub mod shapes;
 pub mod color;
 pub mod direction;
+pub mod paint;
 pub mod compression;
+pub mod serde;
 
 pub use pixel::Pixel;
 pub use color::Color;
-pub use set::PixelSet;
+pub use set::{Extent, LineOfSight, PixelSet};
 pub use shapes::Shape;
 pub use direction::Direction;
-pub use compression::CompressedPixelSet;
\ No newline at end of file
+pub use compression::{CompressedPixelSet, CompressionDictionary, PixelSetArchive};
\ No newline at end of file
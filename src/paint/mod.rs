@@ -0,0 +1,196 @@
+use image::RgbaImage;
+
+use crate::{Color, Direction, PixelSet};
+
+/// Describes how to color the pixels of a set, for use with [`PixelSet::fill_with`].
+///
+/// ## Overview
+///
+/// Unlike [`PixelSet::fill`], which writes a single color, a `Paint` can vary across
+/// the region. Gradients are fitted to the set being filled: a linear gradient spans the
+/// set's extent along its direction, and a radial gradient runs from the set's
+/// [`centroid`](PixelSet::centroid) out to its farthest pixel. Patterns, hatching and
+/// checkerboards are anchored to the image origin, so neighbouring regions line up.
+///
+/// ```rust
+/// use image::DynamicImage;
+/// use pixelset::{Color, Direction, PixelSet, Shape};
+/// use pixelset::paint::Paint;
+/// use pixelset::shapes::Rectangle;
+///
+/// let mut image = DynamicImage::new_rgba8(32, 32);
+/// let area = Rectangle { x: 0, y: 0, width: 32, height: 32 }.set();
+///
+/// area.fill_with(&mut image, Paint::LinearGradient {
+///     direction: Direction::new(1.0, 0.0).unwrap(),
+///     from: Color::new(0, 0, 0, 255),
+///     to: Color::new(255, 255, 255, 255),
+/// });
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Paint<'a> {
+    /// A single uniform color, equivalent to [`PixelSet::fill`].
+    Solid(Color),
+    /// A gradient from `from` to `to` along `direction`, stretched over the set.
+    LinearGradient {
+        /// Direction in which the gradient progresses from `from` to `to`.
+        direction: Direction,
+        /// Color at the trailing edge of the set.
+        from: Color,
+        /// Color at the leading edge of the set.
+        to: Color,
+    },
+    /// A gradient from `from` at the set's centroid to `to` at its farthest pixel.
+    RadialGradient {
+        /// Color at the centroid.
+        from: Color,
+        /// Color at the farthest pixel from the centroid.
+        to: Color,
+    },
+    /// An image repeated in both directions, anchored at the image origin.
+    Pattern(&'a RgbaImage),
+    /// Parallel stripes running along `direction`.
+    ///
+    /// Stripes are `width` pixels thick and repeat every `spacing` pixels. Pixels between
+    /// stripes are set to `background`, or left unchanged if it is `None`.
+    Hatch {
+        /// Direction the stripes run in.
+        direction: Direction,
+        /// Distance between the starts of consecutive stripes, in pixels.
+        spacing: u16,
+        /// Thickness of each stripe, in pixels.
+        width: u16,
+        /// Stripe color.
+        color: Color,
+        /// Color between stripes, if any.
+        background: Option<Color>,
+    },
+    /// Alternating squares of `size` pixels, starting with the first color at the
+    /// image origin.
+    Checkerboard {
+        /// Side length of each square, in pixels.
+        size: u16,
+        /// The two alternating colors.
+        colors: [Color; 2],
+    },
+}
+
+impl Paint<'_> {
+    /// Resolves the paint against a set, precomputing any per-set parameters.
+    pub(crate) fn painter(&self, set: &PixelSet) -> Painter<'_> {
+        match *self {
+            Paint::Solid(color) => Painter::Solid(color),
+            Paint::LinearGradient { direction, from, to } => {
+                let project = |x: u16, y: u16| {
+                    (x as f32 + 0.5) * direction.dx + (y as f32 + 0.5) * direction.dy
+                };
+
+                // Projection is linear along a run, so its endpoints bound the extent.
+                let (min, max) = set.runs().iter().fold((f32::MAX, f32::MIN), |(min, max), run| {
+                    let a = project(run.x_start, run.y);
+                    let b = project(run.x_end(), run.y);
+                    (min.min(a.min(b)), max.max(a.max(b)))
+                });
+
+                Painter::Linear {
+                    direction,
+                    origin: min,
+                    inv_length: inverse(max - min),
+                    from,
+                    to,
+                }
+            }
+            Paint::RadialGradient { from, to } => {
+                let (cx, cy) = set.centroid().unwrap_or_default();
+                let (cx, cy) = (cx as f32 + 0.5, cy as f32 + 0.5);
+                let distance = |x: u16, y: u16| {
+                    (x as f32 + 0.5 - cx).hypot(y as f32 + 0.5 - cy)
+                };
+
+                // The farthest point of a run from any center is one of its endpoints.
+                let radius = set.runs().iter().fold(0.0f32, |radius, run| {
+                    radius
+                        .max(distance(run.x_start, run.y))
+                        .max(distance(run.x_end(), run.y))
+                });
+
+                Painter::Radial { cx, cy, inv_radius: inverse(radius), from, to }
+            }
+            Paint::Pattern(pattern) => Painter::Pattern(pattern),
+            Paint::Hatch { direction, spacing, width, color, background } => Painter::Hatch {
+                // Stripes run along `direction`, so they repeat along its normal.
+                nx: -direction.dy,
+                ny: direction.dx,
+                spacing: spacing.max(1) as f32,
+                width: width as f32,
+                color,
+                background,
+            },
+            Paint::Checkerboard { size, colors } => Painter::Checkerboard {
+                size: size.max(1),
+                colors,
+            },
+        }
+    }
+}
+
+/// A [`Paint`] resolved against a particular set.
+pub(crate) enum Painter<'a> {
+    Solid(Color),
+    Linear { direction: Direction, origin: f32, inv_length: f32, from: Color, to: Color },
+    Radial { cx: f32, cy: f32, inv_radius: f32, from: Color, to: Color },
+    Pattern(&'a RgbaImage),
+    Hatch { nx: f32, ny: f32, spacing: f32, width: f32, color: Color, background: Option<Color> },
+    Checkerboard { size: u16, colors: [Color; 2] },
+}
+
+impl Painter<'_> {
+    /// The uniform color, if every pixel is painted the same.
+    pub(crate) fn solid(&self) -> Option<Color> {
+        match *self {
+            Painter::Solid(color) => Some(color),
+            _ => None,
+        }
+    }
+
+    /// The color for the pixel at `(x, y)`, or `None` to leave it unchanged.
+    pub(crate) fn color_at(&self, x: u16, y: u16) -> Option<Color> {
+        let (px, py) = (x as f32 + 0.5, y as f32 + 0.5);
+
+        match *self {
+            Painter::Solid(color) => Some(color),
+            Painter::Linear { direction, origin, inv_length, from, to } => {
+                let t = (px * direction.dx + py * direction.dy - origin) * inv_length;
+                Some(lerp(from, to, t))
+            }
+            Painter::Radial { cx, cy, inv_radius, from, to } => {
+                let t = (px - cx).hypot(py - cy) * inv_radius;
+                Some(lerp(from, to, t))
+            }
+            Painter::Pattern(pattern) => {
+                let (width, height) = pattern.dimensions();
+                if width == 0 || height == 0 {
+                    return None;
+                }
+                Some(pattern.get_pixel(x as u32 % width, y as u32 % height).0.into())
+            }
+            Painter::Hatch { nx, ny, spacing, width, color, background } => {
+                let offset = (px * nx + py * ny).rem_euclid(spacing);
+                if offset < width { Some(color) } else { background }
+            }
+            Painter::Checkerboard { size, colors } => {
+                Some(colors[(((x / size) as u32 + (y / size) as u32) % 2) as usize])
+            }
+        }
+    }
+}
+
+/// `1 / value`, or `0` for a degenerate zero-length extent.
+fn inverse(value: f32) -> f32 {
+    if value > 0.0 { 1.0 / value } else { 0.0 }
+}
+
+/// Interpolates from `from` to `to` with `t` clamped to `[0, 1]`.
+fn lerp(from: Color, to: Color, t: f32) -> Color {
+    from.blend(to, (t.clamp(0.0, 1.0) * 255.0).round() as u8)
+}
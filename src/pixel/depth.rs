@@ -0,0 +1,79 @@
+use image::{DynamicImage, GenericImage, Pixel as _, Rgba};
+
+use crate::{Color, Pixel};
+use crate::color::{Color16, ColorF32};
+
+impl Pixel {
+    /// Retrieves the color of this pixel with 16 bits per channel.
+    ///
+    /// Lossless for 8- and 16-bit images; floating-point images are clamped to `[0, 1]`
+    /// and rounded. Grayscale images repeat their luma across the RGB channels.
+    pub fn color16(self, image: &DynamicImage) -> Color16 {
+        let (x, y) = (self.x as u32, self.y as u32);
+
+        match image {
+            DynamicImage::ImageLuma16(img) => {
+                let [l] = img.get_pixel(x, y).0;
+                Color16::new(l, l, l, u16::MAX)
+            }
+            DynamicImage::ImageLumaA16(img) => {
+                let [l, a] = img.get_pixel(x, y).0;
+                Color16::new(l, l, l, a)
+            }
+            DynamicImage::ImageRgb16(img) => img.get_pixel(x, y).to_rgba().into(),
+            DynamicImage::ImageRgba16(img) => (*img.get_pixel(x, y)).into(),
+            DynamicImage::ImageRgb32F(_) | DynamicImage::ImageRgba32F(_) => {
+                self.color_f32(image).into()
+            }
+            _ => self.color(image).into(),
+        }
+    }
+
+    /// Retrieves the color of this pixel with `f32` channels in `[0, 1]`.
+    ///
+    /// Lossless for every image format, including values outside `[0, 1]` in
+    /// floating-point images.
+    pub fn color_f32(self, image: &DynamicImage) -> ColorF32 {
+        let (x, y) = (self.x as u32, self.y as u32);
+
+        match image {
+            DynamicImage::ImageRgb32F(img) => img.get_pixel(x, y).to_rgba().into(),
+            DynamicImage::ImageRgba32F(img) => (*img.get_pixel(x, y)).into(),
+            _ => self.color16(image).into(),
+        }
+    }
+
+    /// Sets the color of this pixel from a 16-bit color.
+    ///
+    /// Lossless for 16-bit RGB(A) and floating-point images. Grayscale images store the
+    /// color's luma, and 8-bit images round each channel.
+    pub fn set16(self, image: &mut DynamicImage, color: Color16) {
+        let (x, y) = (self.x as u32, self.y as u32);
+        let rgba: Rgba<u16> = color.into();
+
+        match image {
+            DynamicImage::ImageLuma16(img) => img.put_pixel(x, y, rgba.to_luma()),
+            DynamicImage::ImageLumaA16(img) => img.put_pixel(x, y, rgba.to_luma_alpha()),
+            DynamicImage::ImageRgb16(img) => img.put_pixel(x, y, rgba.to_rgb()),
+            DynamicImage::ImageRgba16(img) => img.put_pixel(x, y, rgba),
+            DynamicImage::ImageRgb32F(_) | DynamicImage::ImageRgba32F(_) => {
+                self.set_f32(image, color.into())
+            }
+            _ => image.put_pixel(x, y, Color::from(color).into()),
+        }
+    }
+
+    /// Sets the color of this pixel from an `f32` color.
+    ///
+    /// Lossless for floating-point images; integer images clamp to `[0, 1]` and round.
+    pub fn set_f32(self, image: &mut DynamicImage, color: ColorF32) {
+        let (x, y) = (self.x as u32, self.y as u32);
+        let rgba: Rgba<f32> = color.into();
+
+        match image {
+            DynamicImage::ImageRgb32F(img) => img.put_pixel(x, y, rgba.to_rgb()),
+            DynamicImage::ImageRgba32F(img) => img.put_pixel(x, y, rgba),
+            _ => self.set16(image, color.into()),
+        }
+    }
+}
@@ -3,6 +3,8 @@ use image::{DynamicImage, GenericImage, GenericImageView};
 use serde::{Deserialize, Serialize};
 use crate::{PixelSet, Color};
 
+mod depth;
+
 const OFFSETS: [(i32, i32); 8] = [
     (-1, -1), 
     (0, -1), 
@@ -0,0 +1,47 @@
+//! Alternative serde representations for [`PixelSet`].
+//!
+//! By default a `PixelSet` serializes as compact zstd-compressed runs (base64 in
+//! human-readable formats), which is opaque in configuration files and diffs. The
+//! modules here are opt-in adapters for use with `#[serde(with = "...")]` that emit
+//! a readable array instead:
+//!
+//! - [`runs`]: horizontal runs, `{ "y": 0, "x": 2, "length": 5 }`
+//! - [`rects`]: a covering of non-overlapping rectangles, `{ "x": 2, "y": 0, "width": 5, "height": 3 }`
+//! - [`pixels`]: individual pixels, `{ "y": 0, "x": 2 }`
+//!
+//! Deserialization accepts entries in any order, and overlapping or adjacent entries
+//! are merged.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use pixelset::PixelSet;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Region {
+//!     name: String,
+//!     #[serde(with = "pixelset::serde::rects")]
+//!     area: PixelSet,
+//! }
+//! ```
+//!
+//! [`PixelSet`]: crate::PixelSet
+
+pub mod pixels;
+pub mod rects;
+pub mod runs;
+
+use crate::set::Run;
+
+/// Build a run from deserialized coordinates, rejecting runs past the `u16` coordinate limit.
+fn checked_run<E: ::serde::de::Error>(y: u16, x_start: u16, length: u16) -> Result<Run, E> {
+    if x_start as u32 + length as u32 > u16::MAX as u32 + 1 {
+        return Err(E::custom(format!(
+            "run at ({}, {}) with length {} exceeds the coordinate limit",
+            x_start, y, length
+        )));
+    }
+
+    Ok(Run { y, x_start, length })
+}
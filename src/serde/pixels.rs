@@ -0,0 +1,30 @@
+//! Serialize a [`PixelSet`] as an array of individual pixels.
+//!
+//! ```json
+//! [{ "y": 0, "x": 2 }, { "y": 0, "x": 3 }]
+//! ```
+//!
+//! This is the most verbose representation, best suited to small, sparse sets.
+//!
+//! [`PixelSet`]: crate::PixelSet
+
+use ::serde::{Deserialize, Deserializer, Serializer};
+
+use crate::{Pixel, PixelSet};
+
+/// Serialize a `PixelSet` as an array of pixels.
+pub fn serialize<S>(pixel_set: &PixelSet, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_seq(pixel_set.iter())
+}
+
+/// Deserialize a `PixelSet` from an array of pixels.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<PixelSet, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let pixels: Vec<Pixel> = Deserialize::deserialize(deserializer)?;
+    Ok(PixelSet::new(pixels))
+}
@@ -0,0 +1,105 @@
+//! Serialize a [`PixelSet`] as an array of non-overlapping rectangles.
+//!
+//! ```json
+//! [{ "x": 2, "y": 0, "width": 5, "height": 3 }]
+//! ```
+//!
+//! Vertically stacked runs with identical horizontal extents are merged into a
+//! single rectangle, so filled rectangular regions collapse to one entry each.
+//!
+//! [`PixelSet`]: crate::PixelSet
+
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::PixelSet;
+use crate::set::Run;
+
+#[derive(Serialize, Deserialize)]
+struct RectRepr {
+    x: u16,
+    y: u16,
+    width: u16,
+    height: u16,
+}
+
+/// Serialize a `PixelSet` as an array of rectangles.
+pub fn serialize<S>(pixel_set: &PixelSet, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_seq(cover(pixel_set.runs()))
+}
+
+/// Deserialize a `PixelSet` from an array of rectangles.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<PixelSet, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let reprs: Vec<RectRepr> = Deserialize::deserialize(deserializer)?;
+
+    let mut runs = Vec::new();
+    for rect in reprs {
+        if rect.y as u32 + rect.height as u32 > u16::MAX as u32 + 1 {
+            return Err(::serde::de::Error::custom(format!(
+                "rectangle at ({}, {}) with height {} exceeds the coordinate limit",
+                rect.x, rect.y, rect.height
+            )));
+        }
+
+        for dy in 0..rect.height {
+            runs.push(super::checked_run(rect.y + dy, rect.x, rect.width)?);
+        }
+    }
+
+    Ok(PixelSet::from_runs(runs))
+}
+
+/// Cover sorted runs with rectangles by stacking runs of equal extent on consecutive rows.
+///
+/// Complexity: `O(k)` plus sorting the resulting rectangles.
+fn cover(runs: &[Run]) -> Vec<RectRepr> {
+    let mut done = Vec::new();
+    // Rectangles reaching the previous row, sorted by `x`.
+    let mut open: Vec<RectRepr> = Vec::new();
+    let mut next: Vec<RectRepr> = Vec::new();
+
+    let mut start = 0;
+    while start < runs.len() {
+        let y = runs[start].y;
+        let end = start + runs[start..].partition_point(|r| r.y == y);
+
+        let mut candidates = open.drain(..).peekable();
+        for run in &runs[start..end] {
+            while let Some(rect) = candidates.next_if(|rect| rect.x < run.x_start) {
+                done.push(rect);
+            }
+
+            match candidates.next_if(|rect| {
+                rect.x == run.x_start
+                    && rect.width == run.length
+                    && rect.y as u32 + rect.height as u32 == y as u32
+            }) {
+                // A rectangle already spanning 65535 rows is closed, and the row starts a new one.
+                Some(mut rect) => match rect.height.checked_add(1) {
+                    Some(height) => {
+                        rect.height = height;
+                        next.push(rect);
+                    }
+                    None => {
+                        done.push(rect);
+                        next.push(RectRepr { x: run.x_start, y, width: run.length, height: 1 });
+                    }
+                },
+                None => next.push(RectRepr { x: run.x_start, y, width: run.length, height: 1 }),
+            }
+        }
+        done.extend(candidates);
+
+        std::mem::swap(&mut open, &mut next);
+        start = end;
+    }
+
+    done.append(&mut open);
+    done.sort_unstable_by_key(|rect| (rect.y, rect.x));
+    done
+}
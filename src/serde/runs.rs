@@ -0,0 +1,45 @@
+//! Serialize a [`PixelSet`] as an array of horizontal runs.
+//!
+//! ```json
+//! [{ "y": 0, "x": 2, "length": 5 }, { "y": 1, "x": 2, "length": 5 }]
+//! ```
+//!
+//! [`PixelSet`]: crate::PixelSet
+
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::PixelSet;
+
+#[derive(Serialize, Deserialize)]
+struct RunRepr {
+    y: u16,
+    x: u16,
+    length: u16,
+}
+
+/// Serialize a `PixelSet` as an array of runs.
+pub fn serialize<S>(pixel_set: &PixelSet, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_seq(pixel_set.runs().iter().map(|run| RunRepr {
+        y: run.y,
+        x: run.x_start,
+        length: run.length,
+    }))
+}
+
+/// Deserialize a `PixelSet` from an array of runs.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<PixelSet, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let reprs: Vec<RunRepr> = Deserialize::deserialize(deserializer)?;
+
+    let runs = reprs
+        .into_iter()
+        .map(|repr| super::checked_run(repr.y, repr.x, repr.length))
+        .collect::<Result<_, _>>()?;
+
+    Ok(PixelSet::from_runs(runs))
+}
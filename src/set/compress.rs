@@ -1,6 +1,6 @@
-use std::io::Result as IoResult;
+use std::io::{Read, Result as IoResult, Write};
 use crate::PixelSet;
-use crate::compression::CompressedPixelSet;
+use crate::compression::{CompressedPixelSet, CompressionDictionary};
 
 impl PixelSet {
     /// Compress this PixelSet into a CompressedPixelSet.
@@ -8,4 +8,44 @@ impl PixelSet {
         let bytes = crate::compression::compress_to_bytes(self)?;
         Ok(CompressedPixelSet::new(bytes))
     }
+
+    /// Compress this PixelSet into a CompressedPixelSet using a trained dictionary.
+    ///
+    /// The dictionary id is stored in the compressed header; the result must be read
+    /// back with [`CompressedPixelSet::decompress_with_dictionary`] and the same dictionary.
+    pub fn compress_with_dictionary(&self, dictionary: &CompressionDictionary) -> IoResult<CompressedPixelSet> {
+        let bytes = crate::compression::compress_with_dictionary_to_bytes(self, dictionary)?;
+        Ok(CompressedPixelSet::new(bytes))
+    }
+
+    /// Compress this PixelSet into a row-indexed CompressedPixelSet.
+    ///
+    /// The set is split into bands of `rows_per_band` rows that are compressed
+    /// independently, so [`CompressedPixelSet::has`] and [`CompressedPixelSet::rows`]
+    /// only decode the bands they touch. Smaller bands make queries cheaper at the
+    /// cost of a lower compression ratio.
+    ///
+    /// Returns an error of kind `InvalidInput` if `rows_per_band` is zero.
+    pub fn compress_indexed(&self, rows_per_band: u16) -> IoResult<CompressedPixelSet> {
+        let bytes = crate::compression::compress_indexed_to_bytes(self, rows_per_band)?;
+        Ok(CompressedPixelSet::new(bytes))
+    }
+
+    /// Stream this PixelSet into `writer` as compressed bytes.
+    ///
+    /// Runs are fed directly through a zstd encoder, so neither the uncompressed
+    /// payload nor the compressed output is buffered in memory. The output is
+    /// byte-compatible with [`CompressedPixelSet::bytes`] and can be read back
+    /// with [`PixelSet::read_compressed`] or [`CompressedPixelSet::decompress`].
+    pub fn write_compressed(&self, writer: &mut impl Write) -> IoResult<()> {
+        crate::compression::compress_to_writer(self, writer)
+    }
+
+    /// Read a PixelSet from a stream of compressed bytes.
+    ///
+    /// Runs are decoded one at a time from a zstd decoder wrapping `reader`.
+    /// Accepts the output of [`PixelSet::write_compressed`] and [`PixelSet::compress`].
+    pub fn read_compressed(reader: impl Read) -> IoResult<Self> {
+        crate::compression::decompress_from_reader(reader)
+    }
 }
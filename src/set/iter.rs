@@ -12,18 +12,17 @@ impl<'a> Iterator for Iter<'a> {
 
     fn next(&mut self) -> Option<Pixel> {
         loop {
-            if let Some((run, x)) = &mut self.current {
-                if *x <= run.x_end() {
-                    let pixel = Pixel::new(*x, run.y);
-                    *x += 1;
-                    return Some(pixel);
-                }
+            // Counting the offset into the run stays in range for runs ending at 65535.
+            if let Some((run, offset)) = &mut self.current
+                && *offset < run.length
+            {
+                let pixel = Pixel::new(run.x_start + *offset, run.y);
+                *offset += 1;
+                return Some(pixel);
             }
 
-            self.current = self.runs.next().map(|&r| (r, r.x_start));
-            if self.current.is_none() {
-                return None;
-            }
+            self.current = self.runs.next().map(|&r| (r, 0));
+            self.current?;
         }
     }
 }
@@ -39,18 +38,17 @@ impl Iterator for IntoIter {
 
     fn next(&mut self) -> Option<Pixel> {
         loop {
-            if let Some((run, x)) = &mut self.current {
-                if *x <= run.x_end() {
-                    let pixel = Pixel::new(*x, run.y);
-                    *x += 1;
-                    return Some(pixel);
-                }
+            // Counting the offset into the run stays in range for runs ending at 65535.
+            if let Some((run, offset)) = &mut self.current
+                && *offset < run.length
+            {
+                let pixel = Pixel::new(run.x_start + *offset, run.y);
+                *offset += 1;
+                return Some(pixel);
             }
 
-            self.current = self.runs.next().map(|r| (r, r.x_start));
-            if self.current.is_none() {
-                return None;
-            }
+            self.current = self.runs.next().map(|r| (r, 0));
+            self.current?;
         }
     }
 }
@@ -4,6 +4,10 @@ mod ops;
 mod new;
 mod iter;
 mod compress;
+#[cfg(feature = "rand")]
+mod random;
+
+pub use ops::{Extent, LineOfSight};
 
 /// A horizontal run-length encoded pixel span.
 /// Encodes all consecutive pixels at a given y-coordinate from x_start to x_start + length - 1.
@@ -18,7 +22,7 @@ impl Run {
     /// The last x-coordinate in this run (inclusive).
     #[inline]
     pub fn x_end(self) -> u16 {
-        self.x_start + self.length - 1
+        self.x_start + (self.length - 1)
     }
 
     /// Check if this run contains the given x-coordinate.
@@ -34,6 +38,39 @@ impl Run {
     }
 }
 
+/// Appends the run covering `x_start..=x_end` on row `y`.
+///
+/// A span of all 65536 columns cannot be encoded in one `u16` length, so its final
+/// column becomes a separate run, matching [`PixelSet::from_runs`].
+pub(crate) fn push_span(runs: &mut Vec<Run>, y: u16, x_start: u16, x_end: u16) {
+    match (x_end - x_start).checked_add(1) {
+        Some(length) => runs.push(Run { y, x_start, length }),
+        None => {
+            runs.push(Run { y, x_start, length: u16::MAX });
+            runs.push(Run { y, x_start: u16::MAX, length: 1 });
+        }
+    }
+}
+
+/// Appends `run`, joining it to the previous run if they touch, as the pieces of a
+/// full-width row stored as two runs can.
+pub(crate) fn push_run(runs: &mut Vec<Run>, run: Run) {
+    if let Some(last) = runs.last_mut()
+        && last.y == run.y
+        && last.x_end() as u32 + 1 == run.x_start as u32
+    {
+        match last.length.checked_add(run.length) {
+            Some(length) => last.length = length,
+            None => {
+                last.length = u16::MAX;
+                runs.push(Run { y: run.y, x_start: u16::MAX, length: 1 });
+            }
+        }
+        return;
+    }
+    runs.push(run);
+}
+
 /// A compact, run-length encoded collection of pixels, optimized for fast set-like
 /// operations and spatial queries on coherent regions.
 ///
@@ -77,6 +114,13 @@ impl PixelSet {
     pub(crate) fn runs(&self) -> &[Run] {
         &self.runs
     }
+
+    /// Get the runs on row `y`, using binary search.
+    pub(crate) fn row_runs(&self, y: u16) -> &[Run] {
+        let start = self.runs.partition_point(|run| run.y < y);
+        let end = start + self.runs[start..].partition_point(|run| run.y == y);
+        &self.runs[start..end]
+    }
 }
 
 impl Serialize for PixelSet {
@@ -2,7 +2,7 @@ use image::{DynamicImage, GenericImageView};
 use radsort::sort_by_key;
 
 use crate::{Pixel, PixelSet};
-use crate::set::Run;
+use crate::set::{Run, push_run, push_span};
 
 impl PixelSet {
     /// Creates a new `PixelSet` from an **unsorted** list of pixels.
@@ -34,6 +34,39 @@ impl PixelSet {
         Self { runs }
     }
 
+    /// Constructs a `PixelSet` from arbitrary runs in any order.
+    ///
+    /// Runs are sorted by `(y, x_start)`, zero-length runs are dropped, and
+    /// overlapping or adjacent runs on the same row are merged, in `O(k)` time
+    /// with the usage of radix sort.
+    pub(crate) fn from_runs(mut runs: Vec<Run>) -> Self {
+        runs.retain(|run| run.length > 0);
+        sort_by_key(&mut runs, |run| run.key());
+
+        let mut merged: Vec<Run> = Vec::with_capacity(runs.len());
+        for run in runs {
+            if let Some(last) = merged.last_mut()
+                && last.y == run.y
+                && run.x_start as u32 <= last.x_end() as u32 + 1
+            {
+                let end = last.x_end().max(run.x_end());
+                match (end - last.x_start).checked_add(1) {
+                    Some(length) => last.length = length,
+                    // A run spanning all 65536 columns cannot be encoded in one `u16` length,
+                    // so the final column stays as its own run.
+                    None => {
+                        last.length = u16::MAX;
+                        merged.push(Run { y: run.y, x_start: u16::MAX, length: 1 });
+                    }
+                }
+                continue;
+            }
+            merged.push(run);
+        }
+
+        Self::from_runs_unchecked(merged)
+    }
+
     /// Returns an empty `PixelSet`.
     pub fn empty() -> Self {
         Self::from_runs_unchecked(vec![])
@@ -43,16 +76,13 @@ impl PixelSet {
     /// given image. Optimized to O(height) by directly generating full-width runs.
     pub fn from_image(image: &DynamicImage) -> Self {
         let (width, height) = image.dimensions();
-        let (width, height) = (width as u16, height as u16);
+        let height = height.min(u16::MAX as u32 + 1);
 
         let mut runs = Vec::with_capacity(height as usize);
-        for y in 0..height {
-            if width > 0 {
-                runs.push(Run {
-                    y,
-                    x_start: 0,
-                    length: width,
-                });
+        if width > 0 {
+            let x_end = width.min(u16::MAX as u32 + 1) - 1;
+            for y in 0..height {
+                push_span(&mut runs, y as u16, 0, x_end as u16);
             }
         }
 
@@ -62,39 +92,15 @@ impl PixelSet {
     /// Encode a sorted, deduplicated pixel list into run-length encoded runs.
     /// Assumes pixels are sorted by (y, x) and contain no duplicates.
     fn encode_runs(pixels: Vec<Pixel>) -> Vec<Run> {
-        if pixels.is_empty() {
-            return vec![];
-        }
-
         let mut runs = Vec::with_capacity(pixels.len());
-        let mut current_y = pixels[0].y;
-        let mut current_x_start = pixels[0].x;
-        let mut current_length = 1u16;
-
-        for i in 1..pixels.len() {
-            let pixel = pixels[i];
-
-            if pixel.y == current_y && pixel.x == current_x_start + current_length {
-                current_length += 1;
-            } else {
-                runs.push(Run {
-                    y: current_y,
-                    x_start: current_x_start,
-                    length: current_length,
-                });
-
-                current_y = pixel.y;
-                current_x_start = pixel.x;
-                current_length = 1;
-            }
+        for pixel in pixels {
+            push_run(&mut runs, Run {
+                y: pixel.y,
+                x_start: pixel.x,
+                length: 1,
+            });
         }
 
-        runs.push(Run {
-            y: current_y,
-            x_start: current_x_start,
-            length: current_length,
-        });
-
         runs
     }
 }
\ No newline at end of file
@@ -0,0 +1,123 @@
+use image::{DynamicImage, GenericImageView, ImageBuffer, Pixel as ImagePixel, RgbaImage};
+
+use crate::{Pixel, PixelSet};
+use crate::set::Run;
+
+impl PixelSet {
+    /// Copies the pixels of this set from `src` into `dst`, translated by `offset`.
+    ///
+    /// Pixel `(x, y)` of `src` is written to `(x + offset.0, y + offset.1)` in `dst`.
+    /// Pixels outside either image are skipped. When both images share a format, each
+    /// run is copied as a single slice; otherwise pixels are converted through
+    /// [`ColorF32`](crate::color::ColorF32), so no precision is lost that `dst` can hold.
+    pub fn copy_to(&self, src: &DynamicImage, dst: &mut DynamicImage, offset: (i32, i32)) {
+        use DynamicImage::*;
+
+        let runs = self.runs();
+        let (src_dims, dst_dims) = (src.dimensions(), dst.dimensions());
+        match (src, &mut *dst) {
+            (ImageLuma8(s), ImageLuma8(d)) => copy_runs(runs, s, d, offset),
+            (ImageLumaA8(s), ImageLumaA8(d)) => copy_runs(runs, s, d, offset),
+            (ImageRgb8(s), ImageRgb8(d)) => copy_runs(runs, s, d, offset),
+            (ImageRgba8(s), ImageRgba8(d)) => copy_runs(runs, s, d, offset),
+            (ImageLuma16(s), ImageLuma16(d)) => copy_runs(runs, s, d, offset),
+            (ImageLumaA16(s), ImageLumaA16(d)) => copy_runs(runs, s, d, offset),
+            (ImageRgb16(s), ImageRgb16(d)) => copy_runs(runs, s, d, offset),
+            (ImageRgba16(s), ImageRgba16(d)) => copy_runs(runs, s, d, offset),
+            (ImageRgb32F(s), ImageRgb32F(d)) => copy_runs(runs, s, d, offset),
+            (ImageRgba32F(s), ImageRgba32F(d)) => copy_runs(runs, s, d, offset),
+            _ => {
+                for run in runs {
+                    let Some((x_start, x_end)) = clip_run(*run, src_dims, dst_dims, offset) else {
+                        continue;
+                    };
+
+                    let dst_y = (run.y as i32 + offset.1) as u16;
+                    for x in x_start..=x_end {
+                        let color = Pixel::new(x, run.y).color_f32(src);
+                        Pixel::new((x as i32 + offset.0) as u16, dst_y).set_f32(dst, color);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Crops the image to this set's [`bounds`](PixelSet::bounds), returning an RGBA image
+    /// in which pixels outside the set are transparent.
+    ///
+    /// Pixel `(min_x, min_y)` of the bounds becomes `(0, 0)` of the result. Returns `None`
+    /// if the set is empty.
+    pub fn extract(&self, image: &DynamicImage) -> Option<RgbaImage> {
+        let (min_x, min_y, max_x, max_y) = self.bounds()?;
+        let width = (max_x - min_x) as u32 + 1;
+        let height = (max_y - min_y) as u32 + 1;
+        let offset = (-(min_x as i32), -(min_y as i32));
+
+        let mut out = RgbaImage::new(width, height);
+        if let DynamicImage::ImageRgba8(src) = image {
+            copy_runs(self.runs(), src, &mut out, offset);
+            return Some(out);
+        }
+
+        // Other formats are converted pixel by pixel into each run's output slice.
+        let src_dims = image.dimensions();
+        let out_raw: &mut [u8] = out.as_mut();
+        for run in self.runs() {
+            let Some((x_start, x_end)) = clip_run(*run, src_dims, (width, height), offset) else {
+                continue;
+            };
+
+            let idx = ((run.y - min_y) as usize * width as usize + (x_start - min_x) as usize) * 4;
+            let len = (x_end - x_start) as usize + 1;
+            for (x, px) in (x_start..=x_end).zip(out_raw[idx..idx + len * 4].chunks_exact_mut(4)) {
+                px.copy_from_slice(&<[u8; 4]>::from(Pixel::new(x, run.y).color(image)));
+            }
+        }
+
+        Some(out)
+    }
+}
+
+/// Copies every run from `src` to `dst` with one slice copy per run.
+fn copy_runs<P: ImagePixel>(
+    runs: &[Run],
+    src: &ImageBuffer<P, Vec<P::Subpixel>>,
+    dst: &mut ImageBuffer<P, Vec<P::Subpixel>>,
+    offset: (i32, i32),
+) {
+    let channels = P::CHANNEL_COUNT as usize;
+    let (src_width, dst_width) = (src.width() as usize, dst.width() as usize);
+    let (src_dims, dst_dims) = (src.dimensions(), dst.dimensions());
+    let dst_raw: &mut [P::Subpixel] = dst.as_mut();
+
+    for run in runs {
+        let Some((x_start, x_end)) = clip_run(*run, src_dims, dst_dims, offset) else {
+            continue;
+        };
+
+        let len = (x_end - x_start) as usize + 1;
+        let src_idx = (run.y as usize * src_width + x_start as usize) * channels;
+        let dst_x = (x_start as i32 + offset.0) as usize;
+        let dst_y = (run.y as i32 + offset.1) as usize;
+        let dst_idx = (dst_y * dst_width + dst_x) * channels;
+
+        dst_raw[dst_idx..dst_idx + len * channels]
+            .copy_from_slice(&src.as_raw()[src_idx..src_idx + len * channels]);
+    }
+}
+
+/// The source x-range of `run` that lies inside `src` and, once translated by `offset`,
+/// inside `dst`, or `None` if no pixel of the run survives.
+fn clip_run(run: Run, src: (u32, u32), dst: (u32, u32), offset: (i32, i32)) -> Option<(u16, u16)> {
+    let dst_y = run.y as i64 + offset.1 as i64;
+    if run.y as u32 >= src.1 || dst_y < 0 || dst_y >= dst.1 as i64 {
+        return None;
+    }
+
+    let start = (run.x_start as i64).max(-(offset.0 as i64));
+    let end = (run.x_end() as i64)
+        .min(src.0 as i64 - 1)
+        .min(dst.0 as i64 - 1 - offset.0 as i64);
+
+    (start <= end).then_some((start as u16, end as u16))
+}
@@ -0,0 +1,65 @@
+use image::DynamicImage;
+
+use crate::PixelSet;
+use crate::color::{Color16, ColorF32};
+
+impl PixelSet {
+    /// Returns an iterator over the 16-bit colors of all pixels in this set.
+    ///
+    /// Like [`as_colors`](PixelSet::as_colors), but without truncating 16-bit images to
+    /// 8 bits per channel. See [`Pixel::color16`](crate::Pixel::color16).
+    pub fn as_colors16<'a>(&'a self, image: &'a DynamicImage) -> impl Iterator<Item = Color16> + 'a {
+        self.iter().map(|pixel| pixel.color16(image))
+    }
+
+    /// Returns an iterator over the `f32` colors of all pixels in this set.
+    ///
+    /// Lossless for every image format. See [`Pixel::color_f32`](crate::Pixel::color_f32).
+    pub fn as_colors_f32<'a>(&'a self, image: &'a DynamicImage) -> impl Iterator<Item = ColorF32> + 'a {
+        self.iter().map(|pixel| pixel.color_f32(image))
+    }
+
+    /// Fills all pixels in this set with a single 16-bit color.
+    pub fn fill16(&self, image: &mut DynamicImage, color: Color16) {
+        for pixel in self {
+            pixel.set16(image, color);
+        }
+    }
+
+    /// Fills all pixels in this set with a single `f32` color.
+    pub fn fill_f32(&self, image: &mut DynamicImage, color: ColorF32) {
+        for pixel in self {
+            pixel.set_f32(image, color);
+        }
+    }
+
+    /// Reads each pixel's 16-bit color, applies a transformation function, and writes
+    /// the result back if it changed.
+    ///
+    /// The high-bit-depth counterpart of [`transform`](PixelSet::transform): 16-bit
+    /// images round-trip through the function without losing precision.
+    pub fn transform16(&self, image: &mut DynamicImage, applier: impl Fn(Color16) -> Color16) {
+        for pixel in self {
+            let found_color = pixel.color16(image);
+            let new_color = applier(found_color);
+            if new_color != found_color {
+                pixel.set16(image, new_color);
+            }
+        }
+    }
+
+    /// Reads each pixel's `f32` color, applies a transformation function, and writes
+    /// the result back if it changed.
+    ///
+    /// The floating-point counterpart of [`transform`](PixelSet::transform): values in
+    /// floating-point images, including those outside `[0, 1]`, are preserved exactly.
+    pub fn transform_f32(&self, image: &mut DynamicImage, applier: impl Fn(ColorF32) -> ColorF32) {
+        for pixel in self {
+            let found_color = pixel.color_f32(image);
+            let new_color = applier(found_color);
+            if new_color != found_color {
+                pixel.set_f32(image, new_color);
+            }
+        }
+    }
+}
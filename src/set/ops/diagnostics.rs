@@ -22,20 +22,23 @@ impl PixelSet {
             }
 
             // Check 2: No overlapping runs on same row
-            if prev.y == curr.y && prev.x_start + prev.length > curr.x_start {
+            let prev_end = prev.x_start as u32 + prev.length as u32;
+            if prev.y == curr.y && prev_end > curr.x_start as u32 {
                 return Err(format!(
                     "Overlap on row {}: [{},{}] overlaps [{},{}]",
-                    prev.y, prev.x_start, prev.x_start + prev.length - 1,
-                    curr.x_start, curr.x_start + curr.length - 1
+                    prev.y, prev.x_start, prev.x_end(),
+                    curr.x_start, curr.x_end()
                 ));
             }
 
-            // Check 3: No adjacent runs on same row (should be merged)
-            if prev.y == curr.y && prev.x_start + prev.length == curr.x_start {
+            // Check 3: No adjacent runs on same row (should be merged), unless together
+            // they span all 65536 columns, which one run cannot hold
+            let full_width = prev.length == u16::MAX && curr.x_start == u16::MAX;
+            if prev.y == curr.y && prev_end == curr.x_start as u32 && !full_width {
                 return Err(format!(
                     "Adjacent on row {}: [{},{}] and [{},{}] should merge",
-                    prev.y, prev.x_start, prev.x_start + prev.length - 1,
-                    curr.x_start, curr.x_start + curr.length - 1
+                    prev.y, prev.x_start, prev.x_end(),
+                    curr.x_start, curr.x_end()
                 ));
             }
         }
@@ -45,6 +48,11 @@ impl PixelSet {
             if run.length == 0 {
                 return Err(format!("Run[{}] has zero length", i));
             }
+
+            // Check 5: Runs must end within the coordinate space
+            if run.x_start as u32 + run.length as u32 > u16::MAX as u32 + 1 {
+                return Err(format!("Run[{}]={:?} extends past column {}", i, run, u16::MAX));
+            }
         }
 
         Ok(())
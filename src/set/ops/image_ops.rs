@@ -1,8 +1,16 @@
+use std::cell::RefCell;
+
 use image::{DynamicImage, GenericImageView};
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use radsort::sort_by_key;
 
 use crate::{Color, Pixel, PixelSet};
+use crate::color::{ColorStats, CompositeMode, Palette};
+use crate::paint::Paint;
+use crate::shapes::Shape;
+
+/// Refinement rounds used by [`PixelSet::dominant_colors`].
+const KMEANS_ITERATIONS: usize = 16;
 
 fn rgba8_get(raw: &[u8], x: u16, y: u16, width: u32) -> Color {
     let idx = (y as usize * width as usize + x as usize) * 4;
@@ -17,6 +25,24 @@ fn rgba8_set(raw: &mut [u8], x: u16, y: u16, width: u32, color: Color) {
     raw[idx + 3] = color.a();
 }
 
+/// Whether column `x` lies in one of the sorted, disjoint `spans`.
+fn covers(spans: &[(u16, u16)], x: u16) -> bool {
+    let i = spans.partition_point(|&(_, end)| end < x);
+    spans.get(i).is_some_and(|&(start, _)| start <= x)
+}
+
+/// Whether columns `x - 1` through `x + 1` are all covered by `spans`, if `inside`, or
+/// all uncovered otherwise. Columns past the coordinate space count as uncovered.
+fn is_uniform(spans: &[(u16, u16)], x: u16, inside: bool) -> bool {
+    let (lo, hi) = (x as i32 - 1, x as i32 + 1);
+    let i = spans.partition_point(|&(_, end)| (end as i32) < lo);
+    match spans.get(i) {
+        Some(&(start, end)) if inside => start as i32 <= lo && end as i32 >= hi,
+        Some(&(start, _)) => start as i32 > hi,
+        None => !inside,
+    }
+}
+
 impl PixelSet {
     /// Applies a color-producing function to each pixel and writes the result to the image.
     ///
@@ -43,6 +69,150 @@ impl PixelSet {
         self.recolor(image, |_| Some(color));
     }
 
+    /// Fills all pixels in this set with a [`Paint`], such as a gradient or pattern.
+    ///
+    /// The paint is resolved against this set once, then rendered run by run. RGBA8
+    /// images are written directly into each run's row slice, and solid paints use a
+    /// slice fill. Pixels outside the image are skipped.
+    pub fn fill_with(&self, image: &mut DynamicImage, paint: Paint) {
+        let painter = paint.painter(self);
+        let (width, height) = image.dimensions();
+
+        let Some(img) = image.as_mut_rgba8() else {
+            for pixel in self {
+                if (pixel.x as u32) < width && (pixel.y as u32) < height
+                    && let Some(color) = painter.color_at(pixel.x, pixel.y)
+                {
+                    pixel.set(image, color);
+                }
+            }
+            return;
+        };
+
+        let raw: &mut [u8] = img.as_mut();
+        for run in self.runs() {
+            if run.y as u32 >= height || run.x_start as u32 >= width {
+                continue;
+            }
+
+            let x_end = (run.x_end() as u32).min(width - 1) as u16;
+            let row = run.y as usize * width as usize;
+            let slice = &mut raw[(row + run.x_start as usize) * 4..(row + x_end as usize + 1) * 4];
+
+            if let Some(color) = painter.solid() {
+                let bytes: [u8; 4] = color.into();
+                for px in slice.chunks_exact_mut(4) {
+                    px.copy_from_slice(&bytes);
+                }
+                continue;
+            }
+
+            for (x, px) in (run.x_start..=x_end).zip(slice.chunks_exact_mut(4)) {
+                if let Some(color) = painter.color_at(x, run.y) {
+                    px.copy_from_slice(&<[u8; 4]>::from(color));
+                }
+            }
+        }
+    }
+
+    /// Fills the part of this set covered by a shape, blending its edges by coverage.
+    ///
+    /// Coverage is estimated from the shape's [`signed_distance`](Shape::signed_distance)
+    /// at each pixel's center. Pixels at least half a pixel inside the shape are filled
+    /// with `color`, pixels at least half a pixel outside are left unchanged, and those in
+    /// between are blended toward `color` with [`Color::blend`]. Only pixels in this set
+    /// and inside the image are touched.
+    ///
+    /// The shape's [`row_spans`](Shape::row_spans) are read once per row, and the distance
+    /// is only measured at pixels next to its edge, where some of the 8 surrounding pixels
+    /// differ from it in membership. Pixels away from the edge are filled or skipped by
+    /// membership alone.
+    pub fn fill_antialiased(&self, image: &mut DynamicImage, shape: &impl Shape, color: Color) {
+        let Some((min_x, min_y, max_x, max_y)) = shape.bounds() else {
+            return;
+        };
+        let (width, height) = image.dimensions();
+
+        // Edge pixels may lie one pixel beyond the shape's own pixels, but never beyond the
+        // coordinate space, even in images larger than it.
+        let (x, y) = (min_x.saturating_sub(1) as u32, min_y.saturating_sub(1) as u32);
+        let x_end = (max_x as u32 + 2).min(width).min(u16::MAX as u32 + 1);
+        let y_end = (max_y as u32 + 2).min(height).min(u16::MAX as u32 + 1);
+        if x >= x_end || y >= y_end {
+            return;
+        }
+
+        let spans = |row: i64| -> Vec<(u16, u16)> {
+            match u16::try_from(row) {
+                Ok(row) => shape.row_spans(row).collect(),
+                Err(_) => Vec::new(),
+            }
+        };
+        // Spans of the rows above, at and below the current one.
+        let mut rows = [Vec::new(), spans(y as i64 - 1), spans(y as i64)];
+
+        for row in y..y_end {
+            rows.rotate_left(1);
+            rows[2] = spans(row as i64 + 1);
+
+            for run in self.row_runs(row as u16) {
+                let start = (run.x_start as u32).max(x);
+                let end = (run.x_end() as u32 + 1).min(x_end);
+                for column in start..end {
+                    let pixel = Pixel::new(column as u16, row as u16);
+                    let inside = covers(&rows[1], pixel.x);
+                    let coverage = if rows.iter().all(|spans| is_uniform(spans, pixel.x, inside)) {
+                        if inside { 1.0 } else { 0.0 }
+                    } else {
+                        let distance = shape.signed_distance(pixel.x as f32 + 0.5, pixel.y as f32 + 0.5);
+                        (0.5 - distance).clamp(0.0, 1.0)
+                    };
+
+                    let opacity = (coverage * 255.0).round() as u8;
+                    if opacity > 0 {
+                        pixel.set(image, pixel.color(image).blend(color, opacity));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Composites a color onto every pixel in this set using the given mode.
+    ///
+    /// `opacity` is the opacity of the composited layer: each pixel is interpolated
+    /// from its backdrop toward the full result of the operator, so `0` leaves the image
+    /// unchanged and `255` applies the operator as-is. Compositing is performed with
+    /// premultiplied alpha, so translucent colors and backdrops combine correctly. See
+    /// [`Color::composite`].
+    pub fn composite(&self, image: &mut DynamicImage, color: Color, mode: CompositeMode, opacity: u8) {
+        if opacity == 0 {
+            return;
+        }
+
+        self.transform(image, |backdrop| {
+            backdrop.mix_premultiplied(backdrop.composite(color, mode), opacity)
+        });
+    }
+
+    /// Replaces every pixel's color with the nearest color in the palette.
+    ///
+    /// Nearness is measured with the palette's [`ColorMetric`](crate::color::ColorMetric),
+    /// and the palette color is written as-is, including its alpha. Lookups are memoized
+    /// per distinct color. Does nothing if the palette is empty.
+    pub fn quantize(&self, image: &mut DynamicImage, palette: &Palette) {
+        if palette.is_empty() {
+            return;
+        }
+
+        let memo = RefCell::new(FxHashMap::default());
+        self.transform(image, |color| {
+            *memo
+                .borrow_mut()
+                .entry(color)
+                .or_insert_with(|| palette.nearest(color).unwrap_or(color))
+        });
+    }
+
     /// Reads each pixel's color from the image, applies a transformation function,
     /// and writes the new color back.
     ///
@@ -166,7 +336,8 @@ impl PixelSet {
     /// Computes the average RGBA color of all pixels in this set.
     ///
     /// Each of the four channels (R, G, B, A) is independently averaged. Integer division
-    /// is used, potentially losing precision in the least significant bit.
+    /// is used, potentially losing precision in the least significant bit. For a rounded
+    /// mean and further statistics, see [`color_stats`](PixelSet::color_stats).
     ///
     /// Returns `None` if the set is empty.
     pub fn mean_color(&self, image: &DynamicImage) -> Option<Color> {
@@ -197,4 +368,53 @@ impl PixelSet {
 
         Some(avg.into())
     }
+
+    /// Computes per-channel statistics of the pixels in this set in a single pass:
+    /// mean, variance, minimum, maximum, median and a full 256-bin histogram.
+    ///
+    /// Colors are read directly from the image buffer for every
+    /// [`DynamicImage`] format. Pixels outside the image are ignored.
+    ///
+    /// Returns `None` if no pixels of the set lie within the image.
+    pub fn color_stats(&self, image: &DynamicImage) -> Option<ColorStats> {
+        let mut histograms = [[0u64; 256]; 4];
+        self.for_each_color(image, |color| {
+            histograms[0][color.r() as usize] += 1;
+            histograms[1][color.g() as usize] += 1;
+            histograms[2][color.b() as usize] += 1;
+            histograms[3][color.a() as usize] += 1;
+        });
+
+        ColorStats::from_histograms(histograms)
+    }
+
+    /// Finds up to `k` representative colors of this set, with the number of pixels
+    /// closest to each, most common first.
+    ///
+    /// Colors are clustered with [`Palette::kmeans`], so near-identical shades (such as
+    /// compression noise) count towards the same dominant color.
+    pub fn dominant_colors(&self, image: &DynamicImage, k: usize) -> Vec<(Color, usize)> {
+        let mut colors = Vec::with_capacity(self.len());
+        self.for_each_color(image, |color| colors.push(color));
+
+        let palette = Palette::kmeans(colors.iter().copied(), k, KMEANS_ITERATIONS);
+        let mut counts = vec![0; palette.len()];
+        let mut memo = FxHashMap::default();
+        for color in colors {
+            let idx = *memo
+                .entry(color)
+                .or_insert_with(|| palette.nearest_index(color).unwrap_or(0));
+            counts[idx] += 1;
+        }
+
+        let mut dominant: Vec<(Color, usize)> = palette
+            .colors()
+            .iter()
+            .copied()
+            .zip(counts)
+            .filter(|&(_, count)| count > 0)
+            .collect();
+        dominant.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        dominant
+    }
 }
@@ -1,6 +1,11 @@
+use std::cell::RefCell;
+
 use image::{DynamicImage, GenericImageView};
+use rustc_hash::FxHashMap;
 
 use crate::{Color, Pixel, PixelSet};
+use crate::color::ColorMetric;
+use crate::set::Run;
 
 impl PixelSet {
     /// Returns `true` if the set contains no pixels.
@@ -117,7 +122,7 @@ impl PixelSet {
             for y in 0..height as u16 {
                 for x in 0..width as u16 {
                     let idx = (y as usize * width as usize + x as usize) * 4;
-                    if &raw[idx..idx + 4] == &query_bytes[..] {
+                    if raw[idx..idx + 4] == query_bytes[..] {
                         matching_pixels.push(Pixel::new(x, y));
                     }
                 }
@@ -134,6 +139,49 @@ impl PixelSet {
         }
     }
 
+    /// Returns a new `PixelSet` containing only pixels whose color in the provided image
+    /// is within `tolerance` of the query color under the given metric.
+    ///
+    /// Useful for matching "almost the same" colors, such as in scanned or compressed
+    /// images. A pixel is included if `color.distance(query, metric) <= tolerance`.
+    /// Distances are memoized per distinct color, so costly metrics like
+    /// [`ColorMetric::Ciede2000`] are computed once per color rather than once per pixel.
+    pub fn select_similar(
+        &self,
+        image: &DynamicImage,
+        query: Color,
+        metric: ColorMetric,
+        tolerance: f32,
+    ) -> Self {
+        let memo = RefCell::new(FxHashMap::default());
+        self.filter_color(image, |color| {
+            *memo
+                .borrow_mut()
+                .entry(color)
+                .or_insert_with(|| color.distance(query, metric) <= tolerance)
+        })
+    }
+
+    /// Returns a new `PixelSet` containing only pixels whose color in the provided image
+    /// is within `tolerance` of **any** of the query colors under the given metric.
+    ///
+    /// Equivalent to the union of [`select_similar`](PixelSet::select_similar) over every
+    /// query color, computed in a single pass.
+    pub fn select_similar_any(
+        &self,
+        image: &DynamicImage,
+        queries: &[Color],
+        metric: ColorMetric,
+        tolerance: f32,
+    ) -> Self {
+        let memo = RefCell::new(FxHashMap::default());
+        self.filter_color(image, |color| {
+            *memo.borrow_mut().entry(color).or_insert_with(|| {
+                queries.iter().any(|&query| color.distance(query, metric) <= tolerance)
+            })
+        })
+    }
+
     /// Returns a modified copy of this `PixelSet` after applying a transformation function.
     ///
     /// The provided function receives a mutable reference to a cloned copy of this set,
@@ -145,6 +193,34 @@ impl PixelSet {
         set
     }
 
+    /// Returns a copy of this set moved by `(dx, dy)`.
+    ///
+    /// Pixels that would land outside the `u16` coordinate space are dropped.
+    ///
+    /// Complexity: `O(k)` where k is the number of runs.
+    pub fn translate(&self, dx: i32, dy: i32) -> Self {
+        let max = u16::MAX as i64;
+        let mut runs = Vec::with_capacity(self.runs.len());
+
+        for run in &self.runs {
+            let y = run.y as i64 + dy as i64;
+            let start = (run.x_start as i64 + dx as i64).max(0);
+            let end = (run.x_end() as i64 + dx as i64).min(max);
+            if !(0..=max).contains(&y) || start > end {
+                continue;
+            }
+
+            runs.push(Run {
+                y: y as u16,
+                x_start: start as u16,
+                length: (end - start + 1) as u16,
+            });
+        }
+
+        // Runs keep their order, but a split full-width row may now overlap itself.
+        PixelSet::from_runs(runs)
+    }
+
     /// Returns the bounding box of this set as (min_x, min_y, max_x, max_y).
     ///
     /// Returns `None` if the set is empty.
@@ -1,4 +1,12 @@
 mod list_ops;
 mod set_ops;
 mod image_ops;
-pub mod diagnostics;
\ No newline at end of file
+mod depth_ops;
+mod copy_ops;
+mod raw;
+mod ray_ops;
+mod sweep_ops;
+pub mod diagnostics;
+
+pub use ray_ops::LineOfSight;
+pub use sweep_ops::Extent;
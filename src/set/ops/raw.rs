@@ -0,0 +1,112 @@
+use image::{DynamicImage, GenericImageView};
+
+use crate::{Color, PixelSet};
+use crate::set::Run;
+
+impl PixelSet {
+    /// Calls `visit` with the color of every pixel in the set, in `(y, x)` order, reading
+    /// whole run slices straight from the image buffer rather than through `get_pixel`.
+    ///
+    /// Pixels outside the image are skipped. Formats wider than 8 bits per channel are
+    /// converted the same way as [`Pixel::color`](crate::Pixel::color).
+    pub(crate) fn for_each_color(&self, image: &DynamicImage, mut visit: impl FnMut(Color)) {
+        let (width, height) = image.dimensions();
+        let rows = RunRows { runs: self.runs(), width, height };
+        let visit = &mut visit;
+
+        match image {
+            DynamicImage::ImageLuma8(img) => {
+                rows.visit(img.as_raw(), 1, |p| gray(p[0], 255), visit)
+            }
+            DynamicImage::ImageLumaA8(img) => {
+                rows.visit(img.as_raw(), 2, |p| gray(p[0], p[1]), visit)
+            }
+            DynamicImage::ImageRgb8(img) => {
+                rows.visit(img.as_raw(), 3, |p| Color::new(p[0], p[1], p[2], 255), visit)
+            }
+            DynamicImage::ImageRgba8(img) => {
+                rows.visit(img.as_raw(), 4, |p| Color::new(p[0], p[1], p[2], p[3]), visit)
+            }
+            DynamicImage::ImageLuma16(img) => {
+                rows.visit(img.as_raw(), 1, |p| gray(u16_to_u8(p[0]), 255), visit)
+            }
+            DynamicImage::ImageLumaA16(img) => {
+                rows.visit(img.as_raw(), 2, |p| gray(u16_to_u8(p[0]), u16_to_u8(p[1])), visit)
+            }
+            DynamicImage::ImageRgb16(img) => {
+                rows.visit(img.as_raw(), 3, |p| rgb(p, u16_to_u8), visit)
+            }
+            DynamicImage::ImageRgba16(img) => {
+                rows.visit(img.as_raw(), 4, |p| rgba(p, u16_to_u8), visit)
+            }
+            DynamicImage::ImageRgb32F(img) => {
+                rows.visit(img.as_raw(), 3, |p| rgb(p, f32_to_u8), visit)
+            }
+            DynamicImage::ImageRgba32F(img) => {
+                rows.visit(img.as_raw(), 4, |p| rgba(p, f32_to_u8), visit)
+            }
+            _ => {
+                for pixel in self {
+                    if (pixel.x as u32) < width && (pixel.y as u32) < height {
+                        visit(pixel.color(image));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Runs clipped to the dimensions of an interleaved image buffer.
+struct RunRows<'a> {
+    runs: &'a [Run],
+    width: u32,
+    height: u32,
+}
+
+impl RunRows<'_> {
+    /// Visits every in-bounds pixel in a buffer with `channels` samples per pixel.
+    fn visit<T>(
+        &self,
+        raw: &[T],
+        channels: usize,
+        to_color: impl Fn(&[T]) -> Color,
+        visit: &mut impl FnMut(Color),
+    ) {
+        let width = self.width as usize;
+
+        for run in self.runs {
+            let x_start = run.x_start as usize;
+            if run.y as u32 >= self.height || x_start >= width {
+                continue;
+            }
+
+            let x_end = (run.x_end() as usize).min(width - 1);
+            let row = run.y as usize * width;
+            let slice = &raw[(row + x_start) * channels..(row + x_end + 1) * channels];
+
+            for sample in slice.chunks_exact(channels) {
+                visit(to_color(sample));
+            }
+        }
+    }
+}
+
+fn gray(luma: u8, alpha: u8) -> Color {
+    Color::new(luma, luma, luma, alpha)
+}
+
+fn rgb<T: Copy>(p: &[T], convert: impl Fn(T) -> u8) -> Color {
+    Color::new(convert(p[0]), convert(p[1]), convert(p[2]), 255)
+}
+
+fn rgba<T: Copy>(p: &[T], convert: impl Fn(T) -> u8) -> Color {
+    Color::new(convert(p[0]), convert(p[1]), convert(p[2]), convert(p[3]))
+}
+
+fn u16_to_u8(sample: u16) -> u8 {
+    ((sample as u32 + 128) / 257) as u8
+}
+
+fn f32_to_u8(sample: f32) -> u8 {
+    (sample.clamp(0.0, 1.0) * 255.0).round() as u8
+}
@@ -1,5 +1,5 @@
 use crate::{Pixel, PixelSet};
-use crate::set::Run;
+use crate::set::{Run, push_run, push_span};
 
 impl PixelSet {
     /// Returns `true` if every pixel in this set is also present in `other`.
@@ -15,20 +15,14 @@ impl PixelSet {
 
             if self_run.y < other_run.y {
                 return false;
-            } else if self_run.y > other_run.y {
+            } else if self_run.y > other_run.y || other_run.x_end() < self_run.x_start {
                 other_idx += 1;
-            } else {
-                if !other.runs[other_idx..].iter().any(|&r| {
-                    r.y == self_run.y
-                        && r.x_start <= self_run.x_start
-                        && r.x_end() >= self_run.x_end()
-                }) {
-                    return false;
-                }
+            } else if other_run.x_start <= self_run.x_start && other.span_end(other_idx) >= self_run.x_end() {
+                // Runs only touch in a full-width row, so a covered run lies entirely within
+                // a single other run or that row.
                 self_idx += 1;
-                while other_idx < other.runs.len() && other.runs[other_idx].y == self_run.y {
-                    other_idx += 1;
-                }
+            } else {
+                return false;
             }
         }
 
@@ -76,6 +70,9 @@ impl PixelSet {
         if idx < self.runs.len() && self.runs[idx].y == pixel.y && self.runs[idx].contains_x(pixel.x) {
             return;
         }
+        if idx > 0 && self.runs[idx - 1].y == pixel.y && self.runs[idx - 1].contains_x(pixel.x) {
+            return;
+        }
 
         let prev_run = idx.checked_sub(1).and_then(|i| {
             if self.runs[i].y == pixel.y {
@@ -91,6 +88,12 @@ impl PixelSet {
             None
         };
 
+        // Filling the last gap in a row completes a full-width row, stored as two runs.
+        let full_row = [
+            Run { y: pixel.y, x_start: 0, length: u16::MAX },
+            Run { y: pixel.y, x_start: u16::MAX, length: 1 },
+        ];
+
         match (prev_run, next_run) {
             (None, None) => {
                 self.runs.insert(idx, Run {
@@ -99,18 +102,28 @@ impl PixelSet {
                     length: 1,
                 });
             }
-            (Some(prev), None) if prev.x_end() + 1 == pixel.x => {
-                self.runs[idx - 1].length += 1;
-            }
-            (None, Some(next)) if pixel.x + 1 == next.x_start => {
-                self.runs[idx].x_start = pixel.x;
-                self.runs[idx].length += 1;
-            }
+            (Some(prev), None) if prev.x_end() + 1 == pixel.x => match prev.length.checked_add(1) {
+                Some(length) => self.runs[idx - 1].length = length,
+                None => self.runs.insert(idx, full_row[1]),
+            },
+            (None, Some(next)) if pixel.x + 1 == next.x_start => match next.length.checked_add(1) {
+                Some(length) => self.runs[idx] = Run { y: pixel.y, x_start: pixel.x, length },
+                None => {
+                    self.runs.splice(idx..=idx, full_row);
+                }
+            },
             (Some(prev), Some(next))
                 if prev.x_end() + 1 == pixel.x && pixel.x + 1 == next.x_start =>
             {
-                self.runs[idx - 1].length = next.x_end() - prev.x_start + 1;
-                self.runs.remove(idx);
+                match (next.x_end() - prev.x_start).checked_add(1) {
+                    Some(length) => {
+                        self.runs[idx - 1].length = length;
+                        self.runs.remove(idx);
+                    }
+                    None => {
+                        self.runs.splice(idx - 1..=idx, full_row);
+                    }
+                }
             }
             (Some(prev), Some(_)) if prev.x_end() + 1 == pixel.x => {
                 // Extend prev even though next exists but isn't adjacent
@@ -147,6 +160,8 @@ impl PixelSet {
             return;
         };
 
+        // A full-width row is stored as two runs, which must rejoin once it has a gap.
+        let full_row = self.runs[run_idx].length == u16::MAX && self.span_end(run_idx) == u16::MAX;
         let run = &mut self.runs[run_idx];
 
         if run.length == 1 {
@@ -165,6 +180,12 @@ impl PixelSet {
             run.length = pixel.x - run.x_start;
             self.runs.insert(run_idx + 1, new_run);
         }
+
+        if full_row && pixel.x < u16::MAX - 1 {
+            let last = if pixel.x == 0 { run_idx } else { run_idx + 1 };
+            self.runs[last].length += 1;
+            self.runs.remove(last + 1);
+        }
     }
 
     /// Returns a new `PixelSet` containing only the pixels that appear in
@@ -189,7 +210,7 @@ impl PixelSet {
                 let x_end = self_run.x_end().min(other_run.x_end());
 
                 if x_start <= x_end {
-                    result.push(Run {
+                    push_run(&mut result, Run {
                         y: self_run.y,
                         x_start,
                         length: x_end - x_start + 1,
@@ -275,24 +296,16 @@ impl PixelSet {
                         break;
                     };
 
-                    if next_run.x_start <= curr_x_end + 1 {
+                    if next_run.x_start as u32 <= curr_x_end as u32 + 1 {
                         curr_x_end = curr_x_end.max(next_run.x_end());
                     } else {
-                        merged.push(Run {
-                            y: self_run.y,
-                            x_start: curr_x_start,
-                            length: curr_x_end - curr_x_start + 1,
-                        });
+                        push_span(&mut merged, self_run.y, curr_x_start, curr_x_end);
                         curr_x_start = next_run.x_start;
                         curr_x_end = next_run.x_end();
                     }
                 }
 
-                merged.push(Run {
-                    y: self_run.y,
-                    x_start: curr_x_start,
-                    length: curr_x_end - curr_x_start + 1,
-                });
+                push_span(&mut merged, self_run.y, curr_x_start, curr_x_end);
 
                 result.extend(merged);
                 self_idx = s_idx;
@@ -310,71 +323,7 @@ impl PixelSet {
     ///
     /// Complexity: `O(k1 + k2)`.
     pub fn xor(&self, other: &Self) -> Self {
-        let mut result = Vec::with_capacity(self.runs.len() + other.runs.len());
-        let mut self_idx = 0;
-        let mut other_idx = 0;
-
-        while self_idx < self.runs.len() && other_idx < other.runs.len() {
-            let a = self.runs[self_idx];
-            let b = other.runs[other_idx];
-
-            if a.y < b.y {
-                result.push(a);
-                self_idx += 1;
-            } else if a.y > b.y {
-                result.push(b);
-                other_idx += 1;
-            } else {
-                let mut a_x = a.x_start;
-                let mut b_x = b.x_start;
-
-                while a_x <= a.x_end() && b_x <= b.x_end() {
-                    if a_x < b_x {
-                        let end = (a.x_end()).min(b_x - 1);
-                        result.push(Run {
-                            y: a.y,
-                            x_start: a_x,
-                            length: end - a_x + 1,
-                        });
-                        a_x = end + 1;
-                    } else if b_x < a_x {
-                        let end = (b.x_end()).min(a_x - 1);
-                        result.push(Run {
-                            y: a.y,
-                            x_start: b_x,
-                            length: end - b_x + 1,
-                        });
-                        b_x = end + 1;
-                    } else {
-                        a_x += 1;
-                        b_x += 1;
-                    }
-                }
-
-                if a_x <= a.x_end() {
-                    result.push(Run {
-                        y: a.y,
-                        x_start: a_x,
-                        length: a.x_end() - a_x + 1,
-                    });
-                }
-                if b_x <= b.x_end() {
-                    result.push(Run {
-                        y: a.y,
-                        x_start: b_x,
-                        length: b.x_end() - b_x + 1,
-                    });
-                }
-
-                self_idx += 1;
-                other_idx += 1;
-            }
-        }
-
-        result.extend_from_slice(&self.runs[self_idx..]);
-        result.extend_from_slice(&other.runs[other_idx..]);
-
-        Self::from_runs_unchecked(result)
+        self.difference(other).or(&other.difference(self))
     }
 
     /// Returns a new `PixelSet` with pixels in this set that are not in `other`,
@@ -394,43 +343,45 @@ impl PixelSet {
             }
 
             if other_idx >= other.runs.len() || other.runs[other_idx].y > self_run.y {
-                result.push(self_run);
+                push_run(&mut result, self_run);
                 self_idx += 1;
                 continue;
             }
 
-            let mut self_x = self_run.x_start;
+            // Columns are counted in `u32` so the cursor can move past the last column.
+            let mut self_x = self_run.x_start as u32;
+            let self_end = self_run.x_end() as u32;
             let mut curr_other_idx = other_idx;
 
-            while self_x <= self_run.x_end() && curr_other_idx < other.runs.len()
+            while self_x <= self_end && curr_other_idx < other.runs.len()
                 && other.runs[curr_other_idx].y == self_run.y
             {
                 let other_run = other.runs[curr_other_idx];
 
-                if other_run.x_end() < self_x {
+                if (other_run.x_end() as u32) < self_x {
                     curr_other_idx += 1;
                     continue;
                 }
 
-                if self_x < other_run.x_start {
-                    let end = (self_run.x_end()).min(other_run.x_start - 1);
-                    result.push(Run {
+                if self_x < other_run.x_start as u32 {
+                    let end = self_end.min(other_run.x_start as u32 - 1);
+                    push_run(&mut result, Run {
                         y: self_run.y,
-                        x_start: self_x,
-                        length: end - self_x + 1,
+                        x_start: self_x as u16,
+                        length: (end - self_x + 1) as u16,
                     });
                     self_x = end + 1;
                 }
 
-                self_x = self_x.max(other_run.x_end() + 1);
+                self_x = self_x.max(other_run.x_end() as u32 + 1);
                 curr_other_idx += 1;
             }
 
-            if self_x <= self_run.x_end() {
-                result.push(Run {
+            if self_x <= self_end {
+                push_run(&mut result, Run {
                     y: self_run.y,
-                    x_start: self_x,
-                    length: self_run.x_end() - self_x + 1,
+                    x_start: self_x as u16,
+                    length: (self_end - self_x + 1) as u16,
                 });
             }
 
@@ -439,4 +390,14 @@ impl PixelSet {
 
         Self::from_runs_unchecked(result)
     }
+
+    /// The last column of the span starting at run `idx`, which continues into the next
+    /// run in a full-width row.
+    fn span_end(&self, idx: usize) -> u16 {
+        let run = self.runs[idx];
+        match self.runs.get(idx + 1) {
+            Some(next) if next.y == run.y && next.x_start as u32 == run.x_end() as u32 + 1 => next.x_end(),
+            _ => run.x_end(),
+        }
+    }
 }
@@ -0,0 +1,102 @@
+use crate::{Direction, PixelSet};
+use crate::set::push_span;
+
+/// The extent of a set measured along a direction, as returned by
+/// [`PixelSet::extent_along`].
+///
+/// Pixels are measured as unit squares, so a single pixel seen along an axis spans a
+/// length and width of 1.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Extent {
+    /// Smallest projection of the set onto the direction.
+    pub min: f32,
+    /// Largest projection of the set onto the direction.
+    pub max: f32,
+    /// Size of the set perpendicular to the direction.
+    pub width: f32,
+}
+
+impl PixelSet {
+    /// Returns the area swept by moving this set `distance` pixels along `dir`: the union
+    /// of every copy of it along the way.
+    ///
+    /// The displacement [`dir.scale(distance)`](Direction::scale) is rounded to whole
+    /// pixels, and the set is translated by each step of the 8-connected line from the
+    /// origin to it, so a single pixel sweeps out a connected line. A negative distance
+    /// sweeps the opposite way. Pixels leaving the `u16` coordinate space are dropped.
+    ///
+    /// Complexity: `O(h k log(h k))` where h is the number of rows the set moves across and
+    /// k the number of runs.
+    pub fn sweep(&self, dir: Direction, distance: f32) -> Self {
+        let (ex, ey) = dir.scale(distance);
+        let max = u16::MAX as f64;
+        let (ex, ey) = ((ex as f64).round().clamp(-max, max), (ey as f64).round().clamp(-max, max));
+        let steps = ex.abs().max(ey.abs()) as i64;
+        if steps == 0 {
+            return self.clone();
+        }
+
+        // Offsets sharing a row shift form a contiguous range of column shifts, so each
+        // row shift stretches runs once instead of copying them per step.
+        let mut shifts: Vec<(i64, i64, i64)> = Vec::new();
+        for step in 0..=steps {
+            let t = step as f64 / steps as f64;
+            let (dx, dy) = ((ex * t).round() as i64, (ey * t).round() as i64);
+            match shifts.last_mut() {
+                Some((y, lo, hi)) if *y == dy => (*lo, *hi) = ((*lo).min(dx), (*hi).max(dx)),
+                _ => shifts.push((dy, dx, dx)),
+            }
+        }
+
+        let mut runs = Vec::with_capacity(self.runs.len() * shifts.len());
+        for &(dy, lo, hi) in &shifts {
+            for run in &self.runs {
+                let y = run.y as i64 + dy;
+                let start = (run.x_start as i64 + lo).max(0);
+                let end = (run.x_end() as i64 + hi).min(max as i64);
+                if !(0..=max as i64).contains(&y) || start > end {
+                    continue;
+                }
+
+                // A stretched run may cover all 65536 columns, more than one run can hold.
+                push_span(&mut runs, y as u16, start as u16, end as u16);
+            }
+        }
+
+        PixelSet::from_runs(runs)
+    }
+
+    /// Measures this set along `dir`: the range of its [projections](Direction::project)
+    /// onto the direction, and its width perpendicular to it.
+    ///
+    /// Returns `None` if the set is empty.
+    ///
+    /// Complexity: `O(k)` where k is the number of runs.
+    pub fn extent_along(&self, dir: Direction) -> Option<Extent> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let across = Direction { dx: -dir.dy, dy: dir.dx };
+        let (mut min, mut max) = (f32::INFINITY, f32::NEG_INFINITY);
+        let (mut min_across, mut max_across) = (f32::INFINITY, f32::NEG_INFINITY);
+
+        // Projections are linear, so the extremes lie at the ends of runs.
+        for run in &self.runs {
+            let y = run.y as f32 + 0.5;
+            for x in [run.x_start as f32 + 0.5, run.x_end() as f32 + 0.5] {
+                let (along, side) = (dir.project(x, y), across.project(x, y));
+                (min, max) = (min.min(along), max.max(along));
+                (min_across, max_across) = (min_across.min(side), max_across.max(side));
+            }
+        }
+
+        // Half the span of one pixel square, which is the same along both directions.
+        let half = (dir.dx.abs() + dir.dy.abs()) / 2.0;
+        Some(Extent {
+            min: min - half,
+            max: max + half,
+            width: max_across - min_across + 2.0 * half,
+        })
+    }
+}
@@ -0,0 +1,62 @@
+use rand::Rng;
+use rand::seq::index;
+
+use crate::{Pixel, PixelSet};
+
+impl PixelSet {
+    /// Picks a uniformly random pixel from the set, or `None` if the set is empty.
+    ///
+    /// Runs in `O(k)` time to build the run-length prefix sums, then `O(log k)` to
+    /// locate the pixel, where `k` is the number of runs.
+    pub fn random_pixel<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<Pixel> {
+        let ends = self.run_ends();
+        let total = *ends.last()?;
+        let idx = rng.random_range(0..total);
+
+        let run_idx = ends.partition_point(|&end| end <= idx);
+        let run = self.runs()[run_idx];
+        let start = ends[run_idx] - run.length as usize;
+
+        Some(Pixel::new(run.x_start + (idx - start) as u16, run.y))
+    }
+
+    /// Picks `n` distinct pixels uniformly at random, without replacement.
+    ///
+    /// Returns a copy of the whole set if it has `n` or fewer pixels. Sampled indices are
+    /// sorted and mapped to pixels in a single pass over the run-length prefix sums.
+    pub fn sample<R: Rng + ?Sized>(&self, n: usize, rng: &mut R) -> PixelSet {
+        let ends = self.run_ends();
+        let total = ends.last().copied().unwrap_or(0);
+        if n >= total {
+            return self.clone();
+        }
+
+        let mut indices = index::sample(rng, total, n).into_vec();
+        indices.sort_unstable();
+
+        let mut pixels = Vec::with_capacity(n);
+        let mut run_idx = 0;
+        for idx in indices {
+            while ends[run_idx] <= idx {
+                run_idx += 1;
+            }
+
+            let run = self.runs()[run_idx];
+            let start = ends[run_idx] - run.length as usize;
+            pixels.push(Pixel::new(run.x_start + (idx - start) as u16, run.y));
+        }
+
+        PixelSet::new_unchecked(pixels)
+    }
+
+    /// Cumulative pixel counts: entry `i` is the number of pixels in runs `0..=i`.
+    fn run_ends(&self) -> Vec<usize> {
+        self.runs()
+            .iter()
+            .scan(0usize, |total, run| {
+                *total += run.length as usize;
+                Some(*total)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,98 @@
+use std::f64::consts::TAU;
+
+use crate::{Pixel, shapes::Shape};
+use crate::shapes::EllipseOutline;
+
+/// Represents an elliptical arc: part of an [`EllipseOutline`] between two angles.
+///
+/// The full ellipse is given by its axis-aligned bounding box and `stroke`, with the
+/// same stroke semantics as [`EllipseOutline`]. Of that outline, only pixels whose
+/// centers lie within the angular range are kept.
+///
+/// ## Angles
+///
+/// Angles are in radians, measured counterclockwise from east around the ellipse
+/// center, like [`Direction::from_angle`](crate::Direction::from_angle). The arc starts
+/// at `start` and covers `sweep` radians: counterclockwise if `sweep` is positive,
+/// clockwise if negative. A pixel's angle is the direction of its center as seen from
+/// the ellipse center.
+///
+/// ## Edge Cases
+///
+/// - A `sweep` of magnitude `2π` or more covers the whole outline
+/// - A `sweep` of 0 or a stroke of 0 produces an empty arc
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Arc {
+    /// Top-left x of the ellipse's bounding box
+    pub x: u16,
+    /// Top-left y of the ellipse's bounding box
+    pub y: u16,
+    /// Width of the ellipse's bounding box
+    pub width: u16,
+    /// Height of the ellipse's bounding box
+    pub height: u16,
+    /// Stroke thickness in pixels
+    pub stroke: u16,
+    /// Angle where the arc begins, in radians
+    pub start: f32,
+    /// Angle covered by the arc, in radians
+    pub sweep: f32,
+}
+
+impl Arc {
+    fn outline(&self) -> EllipseOutline {
+        EllipseOutline {
+            x: self.x,
+            y: self.y,
+            width: self.width,
+            height: self.height,
+            stroke: self.stroke,
+        }
+    }
+
+    /// Whether the pixel's center lies within the arc's angular range.
+    fn in_sweep(&self, pixel: Pixel) -> bool {
+        let sweep = self.sweep as f64;
+        if sweep.abs() >= TAU {
+            return true;
+        }
+
+        let cx = self.x as f64 + self.width as f64 / 2.0;
+        let cy = self.y as f64 + self.height as f64 / 2.0;
+        let angle = (cy - (pixel.y as f64 + 0.5)).atan2(pixel.x as f64 + 0.5 - cx);
+
+        // Offset from the start, in the direction of the sweep.
+        let offset = (angle - self.start as f64) * sweep.signum();
+        offset.rem_euclid(TAU) <= sweep.abs()
+    }
+}
+
+impl Shape for Arc {
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        if self.sweep == 0.0 {
+            return None;
+        }
+        self.outline().bounds()
+    }
+
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)> {
+        // Split the outline's spans wherever they leave the sweep.
+        let mut spans = Vec::new();
+        for (x_start, x_end) in self.outline().row_spans(y).filter(|_| self.sweep != 0.0) {
+            let mut current: Option<(u16, u16)> = None;
+            for x in x_start..=x_end {
+                if self.in_sweep(Pixel::new(x, y)) {
+                    current = Some((current.map_or(x, |(start, _)| start), x));
+                } else if let Some(span) = current.take() {
+                    spans.push(span);
+                }
+            }
+            spans.extend(current);
+        }
+        spans.into_iter()
+    }
+
+    fn has(&self, pixel: Pixel) -> bool {
+        self.sweep != 0.0 && self.outline().has(pixel) && self.in_sweep(pixel)
+    }
+}
@@ -0,0 +1,212 @@
+use crate::{Pixel, PixelSet, shapes::Shape};
+use crate::shapes::LineCap;
+use crate::shapes::line::{Stroke, center};
+use crate::shapes::raster::Point;
+
+/// Maximum distance, in pixels, between a curve and its flattened approximation.
+const TOLERANCE: f64 = 0.2;
+
+/// Limit on subdivision depth, bounding the number of segments at `2^MAX_DEPTH`.
+const MAX_DEPTH: u32 = 16;
+
+/// Represents a quadratic Bézier curve from `start` to `end`, bent toward `control`.
+///
+/// The curve passes through the centers of `start` and `end` and is pulled toward the
+/// center of `control`. It is flattened adaptively into straight segments no further than
+/// a fraction of a pixel from the true curve, then stroked like a [`Polyline`].
+///
+/// ## Stroke Behavior
+///
+/// A `width` of 1 draws a connected single-pixel curve. Wider curves include every pixel
+/// whose center is within `width / 2` of the curve, with ends shaped by `cap`.
+///
+/// ## Edge Cases
+///
+/// - A width of 0 produces an empty curve
+/// - If all points coincide, the curve is drawn like a zero-length [`Line`]
+///
+/// [`Polyline`]: crate::shapes::Polyline
+/// [`Line`]: crate::shapes::Line
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct QuadraticBezier {
+    /// First endpoint
+    pub start: Pixel,
+    /// Control point
+    pub control: Pixel,
+    /// Second endpoint
+    pub end: Pixel,
+    /// Thickness in pixels
+    pub width: u16,
+    /// Shape of the ends of thick curves
+    pub cap: LineCap,
+}
+
+/// Represents a cubic Bézier curve from `start` to `end` with two control points.
+///
+/// The curve leaves `start` heading toward `control1` and arrives at `end` from the
+/// direction of `control2`. Flattening and stroking follow [`QuadraticBezier`].
+///
+/// ## Edge Cases
+///
+/// - A width of 0 produces an empty curve
+/// - If all points coincide, the curve is drawn like a zero-length [`Line`]
+///
+/// [`Line`]: crate::shapes::Line
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct CubicBezier {
+    /// First endpoint
+    pub start: Pixel,
+    /// Control point near `start`
+    pub control1: Pixel,
+    /// Control point near `end`
+    pub control2: Pixel,
+    /// Second endpoint
+    pub end: Pixel,
+    /// Thickness in pixels
+    pub width: u16,
+    /// Shape of the ends of thick curves
+    pub cap: LineCap,
+}
+
+impl QuadraticBezier {
+    /// Creates a single-pixel-wide quadratic curve.
+    pub fn new(start: Pixel, control: Pixel, end: Pixel) -> Self {
+        Self { start, control, end, width: 1, cap: LineCap::Round }
+    }
+
+    /// Returns the curve with a different thickness.
+    pub fn with_width(self, width: u16) -> Self {
+        Self { width, ..self }
+    }
+
+    /// Returns the curve with a different end cap.
+    pub fn with_cap(self, cap: LineCap) -> Self {
+        Self { cap, ..self }
+    }
+
+    /// The equivalent cubic control polygon, in continuous coordinates.
+    fn cubic(&self) -> [Point; 4] {
+        let (p0, p1, p2) = (center(self.start), center(self.control), center(self.end));
+        let toward = |from: Point, to: Point| {
+            (from.0 + (to.0 - from.0) * 2.0 / 3.0, from.1 + (to.1 - from.1) * 2.0 / 3.0)
+        };
+        [p0, toward(p0, p1), toward(p2, p1), p2]
+    }
+
+    fn stroke(&self) -> Stroke {
+        Stroke::new(&flatten(self.cubic()), self.width, self.cap)
+    }
+}
+
+impl CubicBezier {
+    /// Creates a single-pixel-wide cubic curve.
+    pub fn new(start: Pixel, control1: Pixel, control2: Pixel, end: Pixel) -> Self {
+        Self { start, control1, control2, end, width: 1, cap: LineCap::Round }
+    }
+
+    /// Returns the curve with a different thickness.
+    pub fn with_width(self, width: u16) -> Self {
+        Self { width, ..self }
+    }
+
+    /// Returns the curve with a different end cap.
+    pub fn with_cap(self, cap: LineCap) -> Self {
+        Self { cap, ..self }
+    }
+
+    fn cubic(&self) -> [Point; 4] {
+        [self.start, self.control1, self.control2, self.end].map(center)
+    }
+
+    fn stroke(&self) -> Stroke {
+        Stroke::new(&flatten(self.cubic()), self.width, self.cap)
+    }
+}
+
+impl Shape for QuadraticBezier {
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        self.stroke().bounds()
+    }
+
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)> {
+        self.stroke().row_spans(y).into_iter()
+    }
+
+    fn set(&self) -> PixelSet {
+        PixelSet::from_runs(self.stroke().runs())
+    }
+
+    fn iter_pixels(&self) -> impl Iterator<Item = Pixel> {
+        self.set().into_iter()
+    }
+
+    fn len(&self) -> usize {
+        self.set().len()
+    }
+}
+
+impl Shape for CubicBezier {
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        self.stroke().bounds()
+    }
+
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)> {
+        self.stroke().row_spans(y).into_iter()
+    }
+
+    fn set(&self) -> PixelSet {
+        PixelSet::from_runs(self.stroke().runs())
+    }
+
+    fn iter_pixels(&self) -> impl Iterator<Item = Pixel> {
+        self.set().into_iter()
+    }
+
+    fn len(&self) -> usize {
+        self.set().len()
+    }
+}
+
+/// Flattens a cubic curve into a path of points, starting and ending on its endpoints.
+fn flatten(curve: [Point; 4]) -> Vec<Point> {
+    let mut points = vec![curve[0]];
+    subdivide(curve, 0, &mut points);
+    points
+}
+
+/// Appends the end of `curve` once it is flat enough, splitting it in half otherwise.
+fn subdivide(curve: [Point; 4], depth: u32, points: &mut Vec<Point>) {
+    if depth >= MAX_DEPTH || is_flat(curve) {
+        points.push(curve[3]);
+        return;
+    }
+
+    // De Casteljau split at t = 0.5.
+    let mid = |a: Point, b: Point| ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0);
+    let [p0, p1, p2, p3] = curve;
+    let (p01, p12, p23) = (mid(p0, p1), mid(p1, p2), mid(p2, p3));
+    let (p012, p123) = (mid(p01, p12), mid(p12, p23));
+    let p0123 = mid(p012, p123);
+
+    subdivide([p0, p01, p012, p0123], depth + 1, points);
+    subdivide([p0123, p123, p23, p3], depth + 1, points);
+}
+
+/// Whether both control points lie within [`TOLERANCE`] of the chord, which bounds the
+/// distance from the chord to the curve itself.
+fn is_flat([p0, p1, p2, p3]: [Point; 4]) -> bool {
+    let (dx, dy) = (p3.0 - p0.0, p3.1 - p0.1);
+    let length_sq = dx * dx + dy * dy;
+
+    // Distance to the chord segment, so curves overshooting its ends keep splitting.
+    let distance = |p: Point| {
+        let t = if length_sq > 0.0 {
+            (((p.0 - p0.0) * dx + (p.1 - p0.1) * dy) / length_sq).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        (p.0 - p0.0 - t * dx).hypot(p.1 - p0.1 - t * dy)
+    };
+
+    distance(p1) <= TOLERANCE && distance(p2) <= TOLERANCE
+}
@@ -0,0 +1,295 @@
+use crate::{Pixel, PixelSet, shapes::{Rectangle, Shape}};
+use crate::shapes::raster::merge_spans;
+
+/// Pixels in either of two shapes, created by [`Shape::union`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Union<A, B> {
+    a: A,
+    b: B,
+}
+
+/// Pixels in both of two shapes, created by [`Shape::intersection`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Intersection<A, B> {
+    a: A,
+    b: B,
+}
+
+/// Pixels in one shape but not another, created by [`Shape::difference`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Difference<A, B> {
+    a: A,
+    b: B,
+}
+
+/// A shape moved by an offset, created by [`Shape::translate`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Translate<S> {
+    shape: S,
+    dx: i32,
+    dy: i32,
+}
+
+/// A shape limited to a rectangle, created by [`Shape::clip_to`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Clip<S> {
+    shape: S,
+    bounds: Rectangle,
+}
+
+impl<A, B> Union<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<A, B> Intersection<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<A, B> Difference<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<S> Translate<S> {
+    pub(crate) fn new(shape: S, dx: i32, dy: i32) -> Self {
+        Self { shape, dx, dy }
+    }
+}
+
+impl<S> Clip<S> {
+    pub(crate) fn new(shape: S, bounds: Rectangle) -> Self {
+        Self { shape, bounds }
+    }
+}
+
+impl<A: Shape, B: Shape> Shape for Union<A, B> {
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        match (self.a.bounds(), self.b.bounds()) {
+            (Some(a), Some(b)) => Some((a.0.min(b.0), a.1.min(b.1), a.2.max(b.2), a.3.max(b.3))),
+            (a, b) => a.or(b),
+        }
+    }
+
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)> {
+        merge_spans(self.a.row_spans(y).chain(self.b.row_spans(y)).collect()).into_iter()
+    }
+
+    fn set(&self) -> PixelSet {
+        self.a.set().or(&self.b.set())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.a.is_empty() && self.b.is_empty()
+    }
+
+    fn has(&self, pixel: Pixel) -> bool {
+        self.a.has(pixel) || self.b.has(pixel)
+    }
+
+    fn signed_distance(&self, x: f32, y: f32) -> f32 {
+        self.a.signed_distance(x, y).min(self.b.signed_distance(x, y))
+    }
+}
+
+impl<A: Shape, B: Shape> Shape for Intersection<A, B> {
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        intersect_bounds(self.a.bounds()?, self.b.bounds()?)
+    }
+
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)> {
+        let b: Vec<_> = self.b.row_spans(y).collect();
+        intersect_spans(self.a.row_spans(y), b)
+    }
+
+    fn set(&self) -> PixelSet {
+        self.a.set().and(&self.b.set())
+    }
+
+    fn has(&self, pixel: Pixel) -> bool {
+        self.a.has(pixel) && self.b.has(pixel)
+    }
+
+    fn signed_distance(&self, x: f32, y: f32) -> f32 {
+        self.a.signed_distance(x, y).max(self.b.signed_distance(x, y))
+    }
+}
+
+impl<A: Shape, B: Shape> Shape for Difference<A, B> {
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        self.a.bounds()
+    }
+
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)> {
+        let mut removed = self.b.row_spans(y).peekable();
+        let mut spans = Vec::new();
+
+        for (x_start, x_end) in self.a.row_spans(y) {
+            let mut start = x_start as u32;
+            while let Some(&(cut_start, cut_end)) = removed.peek() {
+                if (cut_end as u32) < start {
+                    removed.next();
+                    continue;
+                }
+                if cut_start > x_end {
+                    break;
+                }
+                if cut_start as u32 > start {
+                    spans.push((start as u16, cut_start - 1));
+                }
+                start = cut_end as u32 + 1;
+
+                // A cut reaching past this span may also overlap the next one.
+                if cut_end >= x_end {
+                    break;
+                }
+                removed.next();
+            }
+            if start <= x_end as u32 {
+                spans.push((start as u16, x_end));
+            }
+        }
+        spans.into_iter()
+    }
+
+    fn set(&self) -> PixelSet {
+        self.a.set().difference(&self.b.set())
+    }
+
+    fn has(&self, pixel: Pixel) -> bool {
+        self.a.has(pixel) && !self.b.has(pixel)
+    }
+
+    fn signed_distance(&self, x: f32, y: f32) -> f32 {
+        self.a.signed_distance(x, y).max(-self.b.signed_distance(x, y))
+    }
+}
+
+impl<S: Shape> Shape for Translate<S> {
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        let (min_x, min_y, max_x, max_y) = self.shape.bounds()?;
+        let (min_x, max_x) = shift_span((min_x, max_x), self.dx)?;
+        let (min_y, max_y) = shift_span((min_y, max_y), self.dy)?;
+        Some((min_x, min_y, max_x, max_y))
+    }
+
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)> {
+        let source = u16::try_from(y as i64 - self.dy as i64).ok();
+        let spans = source.into_iter().flat_map(move |source| self.shape.row_spans(source));
+        spans.filter_map(|span| shift_span(span, self.dx))
+    }
+
+    fn set(&self) -> PixelSet {
+        self.shape.set().translate(self.dx, self.dy)
+    }
+
+    fn has(&self, pixel: Pixel) -> bool {
+        offset(pixel, -(self.dx as i64), -(self.dy as i64))
+            .is_some_and(|source| self.shape.has(source))
+    }
+
+    fn signed_distance(&self, x: f32, y: f32) -> f32 {
+        self.shape.signed_distance(x - self.dx as f32, y - self.dy as f32)
+    }
+}
+
+impl<S: Shape> Shape for Clip<S> {
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        intersect_bounds(self.shape.bounds()?, self.bounds.bounds()?)
+    }
+
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)> {
+        intersect_spans(self.shape.row_spans(y), self.bounds.row_spans(y).collect())
+    }
+
+    fn set(&self) -> PixelSet {
+        self.shape.set().and(&self.bounds.set())
+    }
+
+    fn has(&self, pixel: Pixel) -> bool {
+        self.bounds.has(pixel) && self.shape.has(pixel)
+    }
+
+    fn signed_distance(&self, x: f32, y: f32) -> f32 {
+        self.shape.signed_distance(x, y).max(self.bounds.signed_distance(x, y))
+    }
+}
+
+impl<S: Shape> Shape for &S {
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        (**self).bounds()
+    }
+
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)> {
+        (**self).row_spans(y)
+    }
+
+    fn set(&self) -> PixelSet {
+        (**self).set()
+    }
+
+    fn iter_pixels(&self) -> impl Iterator<Item = Pixel> {
+        (**self).iter_pixels()
+    }
+
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    fn is_empty(&self) -> bool {
+        (**self).is_empty()
+    }
+
+    fn has(&self, pixel: Pixel) -> bool {
+        (**self).has(pixel)
+    }
+
+    fn signed_distance(&self, x: f32, y: f32) -> f32 {
+        (**self).signed_distance(x, y)
+    }
+}
+
+/// `pixel` moved by `(dx, dy)`, if it stays within the coordinate space.
+fn offset(pixel: Pixel, dx: i64, dy: i64) -> Option<Pixel> {
+    let x = u16::try_from(pixel.x as i64 + dx).ok()?;
+    let y = u16::try_from(pixel.y as i64 + dy).ok()?;
+    Some(Pixel::new(x, y))
+}
+
+/// An inclusive span moved by `offset` and clipped to the coordinate space.
+fn shift_span((start, end): (u16, u16), offset: i32) -> Option<(u16, u16)> {
+    let start = (start as i64 + offset as i64).max(0);
+    let end = (end as i64 + offset as i64).min(u16::MAX as i64);
+    (start <= end).then_some((start as u16, end as u16))
+}
+
+fn intersect_bounds(
+    a: (u16, u16, u16, u16),
+    b: (u16, u16, u16, u16),
+) -> Option<(u16, u16, u16, u16)> {
+    let bounds = (a.0.max(b.0), a.1.max(b.1), a.2.min(b.2), a.3.min(b.3));
+    (bounds.0 <= bounds.2 && bounds.1 <= bounds.3).then_some(bounds)
+}
+
+/// Intersection of two sorted lists of disjoint spans.
+fn intersect_spans(
+    a: impl Iterator<Item = (u16, u16)>,
+    b: Vec<(u16, u16)>,
+) -> std::vec::IntoIter<(u16, u16)> {
+    let mut spans = Vec::new();
+    let mut first = 0;
+
+    for (start, end) in a {
+        while first < b.len() && b[first].1 < start {
+            first += 1;
+        }
+        for &(other_start, other_end) in b[first..].iter().take_while(|span| span.0 <= end) {
+            spans.push((start.max(other_start), end.min(other_end)));
+        }
+    }
+    spans.into_iter()
+}
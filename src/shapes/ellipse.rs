@@ -1,4 +1,5 @@
-use crate::{Pixel, PixelSet, shapes::Shape};
+use crate::{Pixel, shapes::{Rectangle, Shape}};
+use crate::shapes::raster;
 
 /// Represents a filled ellipse within a bounding box.
 ///
@@ -29,21 +30,37 @@ pub struct Ellipse {
 }
 
 impl Shape for Ellipse {
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        Rectangle { x: self.x, y: self.y, width: self.width, height: self.height }.bounds()
+    }
+
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)> {
+        let row = self.bounds().filter(|&(_, min_y, _, max_y)| min_y <= y && y <= max_y);
+        let span = row.and_then(|(min_x, _, max_x, _)| {
+            let cx = self.x as f64 + self.width as f64 / 2.0;
+            let cy = self.y as f64 + self.height as f64 / 2.0;
+            let (rx, ry) = (self.width as f64 / 2.0, self.height as f64 / 2.0);
+
+            let dy = y as f64 + 0.5 - cy;
+            let half = rx * (1.0 - (dy * dy) / (ry * ry)).max(0.0).sqrt();
+            raster::trim_span((cx - half, cx + half), (min_x, max_x), |x| {
+                self.has(Pixel::new(x, y))
+            })
+        });
+        span.into_iter()
+    }
+
     fn has(&self, pixel: Pixel) -> bool {
         let Pixel { x, y } = pixel;
 
-        let x0 = self.x;
-        let y0 = self.y;
-        let x1 = self.x + self.width;
-        let y1 = self.y + self.height;
-
-        if x < x0 || x >= x1 || y < y0 || y >= y1 {
+        let bbox = Rectangle { x: self.x, y: self.y, width: self.width, height: self.height };
+        if !bbox.has(pixel) {
             return false;
         }
 
         // Center of the ellipse
-        let cx = x0 as f64 + self.width as f64 / 2.0;
-        let cy = y0 as f64 + self.height as f64 / 2.0;
+        let cx = self.x as f64 + self.width as f64 / 2.0;
+        let cy = self.y as f64 + self.height as f64 / 2.0;
 
         let rx = self.width as f64 / 2.0;
         let ry = self.height as f64 / 2.0;
@@ -58,28 +75,13 @@ impl Shape for Ellipse {
         (dx * dx) / (rx * rx) + (dy * dy) / (ry * ry) <= 1.0
     }
 
-    fn iter_pixels(&self) -> impl Iterator<Item = Pixel> {
-        let x0 = self.x;
-        let y0 = self.y;
-        let x1 = self.x + self.width;
-        let y1 = self.y + self.height;
-
-        (y0..y1).flat_map(move |y| {
-            (x0..x1).filter_map(move |x| {
-                let p = Pixel::new(x, y);
-                self.has(p).then_some(p)
-            })
-        })
-    }
-
-    fn set(&self) -> PixelSet {
-        let mut pixels = Vec::with_capacity(self.len());
-        pixels.extend(self.iter_pixels());
-        PixelSet::new_unchecked(pixels)
-    }
+    fn signed_distance(&self, x: f32, y: f32) -> f32 {
+        if self.width == 0 || self.height == 0 {
+            return f32::INFINITY;
+        }
 
-    fn len(&self) -> usize {
-        // Cheap upper bound; exact count requires scanning anyway
-        self.width as usize * self.height as usize
+        let (rx, ry) = (self.width as f64 / 2.0, self.height as f64 / 2.0);
+        let center = (self.x as f64 + rx, self.y as f64 + ry);
+        raster::ellipse_distance(center, (rx, ry), (x as f64, y as f64)) as f32
     }
 }
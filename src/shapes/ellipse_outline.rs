@@ -1,4 +1,5 @@
-use crate::{Pixel, PixelSet, shapes::Shape};
+use crate::{Pixel, shapes::{Rectangle, Shape}};
+use crate::shapes::raster;
 
 /// Represents an ellipse border with adjustable stroke width.
 ///
@@ -56,21 +57,62 @@ impl EllipseOutline {
 }
 
 impl Shape for EllipseOutline {
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        if self.stroke == 0 {
+            return None;
+        }
+        Rectangle { x: self.x, y: self.y, width: self.width, height: self.height }.bounds()
+    }
+
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)> {
+        let row = self.bounds().filter(|&(_, min_y, _, max_y)| min_y <= y && y <= max_y);
+        let spans = row.and_then(|(min_x, _, max_x, _)| {
+            let cx = self.x as f64 + self.width as f64 / 2.0;
+            let cy = self.y as f64 + self.height as f64 / 2.0;
+            let dy = y as f64 + 0.5 - cy;
+
+            // Columns of the ellipse with the given semi-axes on this row.
+            let span = |rx: f64, ry: f64| {
+                if rx <= 0.0 || ry <= 0.0 {
+                    return None;
+                }
+                let half = rx * (1.0 - (dy * dy) / (ry * ry)).max(0.0).sqrt();
+                raster::trim_span((cx - half, cx + half), (min_x, max_x), |x| {
+                    Self::inside_ellipse(x, y, cx, cy, rx, ry)
+                })
+            };
+
+            let (x_start, x_end) = span(self.width as f64 / 2.0, self.height as f64 / 2.0)?;
+            let inner_w = (self.width as u32).saturating_sub(self.stroke as u32 * 2) as u16;
+            let inner_h = (self.height as u32).saturating_sub(self.stroke as u32 * 2) as u16;
+            let inner = if inner_w == 0 || inner_h == 0 {
+                None
+            } else {
+                span(inner_w as f64 / 2.0, inner_h as f64 / 2.0)
+            };
+
+            Some(match inner {
+                Some((inner_start, inner_end)) => [
+                    (inner_start > x_start).then(|| (x_start, inner_start - 1)),
+                    (inner_end < x_end).then(|| (inner_end + 1, x_end)),
+                ],
+                None => [Some((x_start, x_end)), None],
+            })
+        });
+        spans.into_iter().flatten().flatten()
+    }
+
     fn has(&self, pixel: Pixel) -> bool {
         let Pixel { x, y } = pixel;
 
-        let x0 = self.x;
-        let y0 = self.y;
-        let x1 = self.x + self.width;
-        let y1 = self.y + self.height;
-
-        if x < x0 || x >= x1 || y < y0 || y >= y1 {
+        let bbox = Rectangle { x: self.x, y: self.y, width: self.width, height: self.height };
+        if !bbox.has(pixel) {
             return false;
         }
 
         // Outer ellipse
-        let cx = x0 as f64 + self.width as f64 / 2.0;
-        let cy = y0 as f64 + self.height as f64 / 2.0;
+        let cx = self.x as f64 + self.width as f64 / 2.0;
+        let cy = self.y as f64 + self.height as f64 / 2.0;
 
         let rx = self.width as f64 / 2.0;
         let ry = self.height as f64 / 2.0;
@@ -80,8 +122,8 @@ impl Shape for EllipseOutline {
         }
 
         // Inner ellipse (shrunk by stroke)
-        let inner_w = self.width.saturating_sub(self.stroke * 2);
-        let inner_h = self.height.saturating_sub(self.stroke * 2);
+        let inner_w = (self.width as u32).saturating_sub(self.stroke as u32 * 2) as u16;
+        let inner_h = (self.height as u32).saturating_sub(self.stroke as u32 * 2) as u16;
 
         if inner_w == 0 || inner_h == 0 {
             return true;
@@ -93,35 +135,20 @@ impl Shape for EllipseOutline {
         !Self::inside_ellipse(x, y, cx, cy, irx, iry)
     }
 
-    fn iter_pixels(&self) -> impl Iterator<Item = Pixel> {
-        let x0 = self.x;
-        let y0 = self.y;
-        let x1 = self.x + self.width;
-        let y1 = self.y + self.height;
-
-        (y0..y1).flat_map(move |y| {
-            (x0..x1).filter_map(move |x| {
-                let p = Pixel::new(x, y);
-                self.has(p).then_some(p)
-            })
-        })
-    }
-
-    fn set(&self) -> PixelSet {
-        let mut pixels = Vec::with_capacity(self.len());
-        pixels.extend(self.iter_pixels());
-        PixelSet::new_unchecked(pixels)
-    }
-
-    fn len(&self) -> usize {
-        // Same reasoning as RectangleOutline: outer minus inner area
-        let outer = self.width as usize * self.height as usize;
-
-        let inner_w = self.width.saturating_sub(self.stroke * 2) as usize;
-        let inner_h = self.height.saturating_sub(self.stroke * 2) as usize;
+    fn signed_distance(&self, x: f32, y: f32) -> f32 {
+        if self.stroke == 0 || self.width == 0 || self.height == 0 {
+            return f32::INFINITY;
+        }
 
-        let inner = inner_w * inner_h;
+        let (rx, ry) = (self.width as f64 / 2.0, self.height as f64 / 2.0);
+        let center = (self.x as f64 + rx, self.y as f64 + ry);
+        let point = (x as f64, y as f64);
+        let outer = raster::ellipse_distance(center, (rx, ry), point);
 
-        outer - inner
+        let inner = (rx - self.stroke as f64, ry - self.stroke as f64);
+        if inner.0 <= 0.0 || inner.1 <= 0.0 {
+            return outer as f32;
+        }
+        outer.max(-raster::ellipse_distance(center, inner, point)) as f32
     }
 }
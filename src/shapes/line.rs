@@ -0,0 +1,321 @@
+use crate::{Direction, Pixel, PixelSet, shapes::Shape};
+use crate::set::Run;
+use crate::shapes::raster::{self, Boundary, Point, Region};
+
+/// How the ends of a thick [`Line`] or [`Polyline`] are drawn.
+///
+/// [`Polyline`]: crate::shapes::Polyline
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum LineCap {
+    /// Ends are semicircles centered on the endpoints.
+    #[default]
+    Round,
+    /// Ends are squared off, extending half the width past the endpoints.
+    Square,
+}
+
+/// Represents a straight line segment between two pixels.
+///
+/// A line of `width` 1 is rasterized with Bresenham's algorithm, producing the familiar
+/// single-pixel staircase with exactly one pixel per step along its major axis. Wider
+/// lines include every pixel whose center is within `width / 2` of the segment between
+/// the endpoint pixel centers, with ends shaped by [`LineCap`].
+///
+/// ## Edge Cases
+///
+/// - A line whose endpoints coincide is a single pixel (width 1), or a dot shaped by its cap
+/// - A width of 0 produces an empty line
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Line {
+    /// First endpoint
+    pub start: Pixel,
+    /// Second endpoint
+    pub end: Pixel,
+    /// Thickness in pixels
+    pub width: u16,
+    /// Shape of the ends of thick lines
+    pub cap: LineCap,
+}
+
+impl Line {
+    /// Creates a single-pixel-wide line between two pixels.
+    pub fn new(start: Pixel, end: Pixel) -> Self {
+        Self { start, end, width: 1, cap: LineCap::Round }
+    }
+
+    /// Creates a single-pixel-wide line from `start`, extending `length` pixels
+    /// in `direction`.
+    ///
+    /// The end pixel is rounded to the nearest pixel and clamped to the coordinate space.
+    pub fn from_direction(start: Pixel, direction: Direction, length: f32) -> Self {
+        let (dx, dy) = direction.scale(length);
+        let clamp = |v: f32| v.round().clamp(0.0, u16::MAX as f32) as u16;
+        let end = Pixel::new(clamp(start.x as f32 + dx), clamp(start.y as f32 + dy));
+        Self::new(start, end)
+    }
+
+    /// Returns the line with a different thickness.
+    pub fn with_width(self, width: u16) -> Self {
+        Self { width, ..self }
+    }
+
+    /// Returns the line with a different end cap.
+    pub fn with_cap(self, cap: LineCap) -> Self {
+        Self { cap, ..self }
+    }
+
+    /// Generates the runs of this line. Runs are unsorted for lines drawn upwards.
+    pub(crate) fn runs(&self) -> Vec<Run> {
+        match self.width {
+            0 => Vec::new(),
+            1 => bresenham_runs(self.start, self.end),
+            _ => self.region().runs(),
+        }
+    }
+
+    /// The region covered by a line wider than one pixel.
+    fn region(&self) -> Region<impl Fn(f64) -> Vec<(f64, f64)>> {
+        let a = center(self.start);
+        let b = center(self.end);
+        let radius = self.width as f64 / 2.0;
+        let along = (-radius, (b.0 - a.0).hypot(b.1 - a.1) + radius);
+        let cap = self.cap;
+
+        let (x_extent, y_extent) = match cap {
+            LineCap::Round => raster::capsule_extent(a, b, radius),
+            LineCap::Square => raster::band_extent(a, b, along, radius),
+        };
+
+        Region {
+            boundary: Boundary::Closed,
+            x_extent,
+            y_extent,
+            row_intervals: move |y| {
+                let interval = match cap {
+                    LineCap::Round => raster::capsule_interval(a, b, radius, y),
+                    LineCap::Square => raster::band_interval(a, b, along, radius, y),
+                };
+                interval.into_iter().collect()
+            },
+        }
+    }
+}
+
+impl Shape for Line {
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        match self.width {
+            0 => None,
+            1 => Some((
+                self.start.x.min(self.end.x),
+                self.start.y.min(self.end.y),
+                self.start.x.max(self.end.x),
+                self.start.y.max(self.end.y),
+            )),
+            _ => self.region().bounds(),
+        }
+    }
+
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)> {
+        let spans = match self.width {
+            0 => Vec::new(),
+            1 => bresenham_span(self.start, self.end, y).into_iter().collect(),
+            _ => self.region().row_spans(y),
+        };
+        spans.into_iter()
+    }
+
+    fn set(&self) -> PixelSet {
+        PixelSet::from_runs(self.runs())
+    }
+}
+
+/// The center of a pixel in continuous coordinates.
+pub(crate) fn center(pixel: Pixel) -> Point {
+    (pixel.x as f64 + 0.5, pixel.y as f64 + 0.5)
+}
+
+/// A stroke along the path through a list of points, given in continuous coordinates.
+///
+/// A `width` of 1 joins the pixels containing consecutive points with Bresenham lines.
+/// Wider strokes are the union of capsules around each segment, so joints are always
+/// rounded while the two ends of the path are shaped by `cap`.
+///
+/// The stroke is kept as separate pieces that are each rasterized row by row, so a single
+/// row is found without rasterizing the rest of the stroke.
+pub(crate) struct Stroke {
+    /// Bresenham lines between pixels, for strokes of width 1.
+    lines: Vec<(Pixel, Pixel)>,
+    /// Regions making up wider strokes.
+    pieces: Vec<Piece>,
+}
+
+/// A region of a [`Stroke`] wider than one pixel.
+enum Piece {
+    /// The points within `radius` of the segment between two points.
+    Capsule(Point, Point, f64),
+    /// The half-width square beyond the end of the path at `end`, pointing at `outward`.
+    Square { end: Point, outward: Point, radius: f64 },
+}
+
+impl Stroke {
+    pub(crate) fn new(points: &[Point], width: u16, cap: LineCap) -> Self {
+        let mut stroke = Self { lines: Vec::new(), pieces: Vec::new() };
+        let (Some(&first), Some(&last)) = (points.first(), points.last()) else {
+            return stroke;
+        };
+
+        if width == 1 {
+            let to_pixel = |(x, y): Point| {
+                let clamp = |v: f64| v.floor().clamp(0.0, u16::MAX as f64) as u16;
+                Pixel::new(clamp(x), clamp(y))
+            };
+            let mut pixels: Vec<Pixel> = points.iter().map(|&p| to_pixel(p)).collect();
+            pixels.dedup();
+
+            stroke.lines = match pixels.len() {
+                1 => vec![(pixels[0], pixels[0])],
+                _ => pixels.windows(2).map(|pair| (pair[0], pair[1])).collect(),
+            };
+        } else if width > 1 {
+            let radius = width as f64 / 2.0;
+            let segments = points.windows(2).map(|pair| (pair[0], pair[1]));
+            let lone = (points.len() == 1).then_some((first, first));
+            stroke.pieces = segments.chain(lone).map(|(a, b)| Piece::Capsule(a, b, radius)).collect();
+
+            if cap == LineCap::Square {
+                // Extend each end outward by a half-width square, along the path's end tangents.
+                // A path collapsed to one point extends both ways, like a zero-length line.
+                let ends = [
+                    (first, first_other(first, points.iter()).unwrap_or((first.0 - 1.0, first.1))),
+                    (last, first_other(last, points.iter().rev()).unwrap_or((last.0 + 1.0, last.1))),
+                ];
+                for (end, toward) in ends {
+                    let outward = (2.0 * end.0 - toward.0, 2.0 * end.1 - toward.1);
+                    stroke.pieces.push(Piece::Square { end, outward, radius });
+                }
+            }
+        }
+        stroke
+    }
+
+    /// Bounding box of the pixels the stroke may cover.
+    pub(crate) fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        let lines = self.lines.iter().map(|&(a, b)| (a.x.min(b.x), a.y.min(b.y), a.x.max(b.x), a.y.max(b.y)));
+        raster::union_bounds(lines.chain(self.pieces.iter().filter_map(|piece| piece.region().bounds())))
+    }
+
+    /// Sorted, disjoint and non-adjacent ranges of pixel x-coordinates covered on row `y`.
+    pub(crate) fn row_spans(&self, y: u16) -> Vec<(u16, u16)> {
+        let lines = self.lines.iter().filter_map(|&(a, b)| bresenham_span(a, b, y));
+        let pieces = self.pieces.iter().flat_map(|piece| piece.region().row_spans(y));
+        raster::merge_spans(lines.chain(pieces).collect())
+    }
+
+    /// Runs of the stroke, unsorted and possibly overlapping.
+    pub(crate) fn runs(&self) -> Vec<Run> {
+        let lines = self.lines.iter().flat_map(|&(a, b)| bresenham_runs(a, b));
+        lines.chain(self.pieces.iter().flat_map(|piece| piece.region().runs())).collect()
+    }
+}
+
+impl Piece {
+    fn region(&self) -> Region<impl Fn(f64) -> Vec<(f64, f64)> + '_> {
+        let (x_extent, y_extent) = match *self {
+            Piece::Capsule(a, b, radius) => raster::capsule_extent(a, b, radius),
+            Piece::Square { end, outward, radius } => raster::band_extent(end, outward, (0.0, radius), radius),
+        };
+
+        Region {
+            boundary: Boundary::Closed,
+            x_extent,
+            y_extent,
+            row_intervals: move |y| {
+                let interval = match *self {
+                    Piece::Capsule(a, b, radius) => raster::capsule_interval(a, b, radius, y),
+                    Piece::Square { end, outward, radius } => {
+                        raster::band_interval(end, outward, (0.0, radius), radius, y)
+                    }
+                };
+                interval.into_iter().collect()
+            },
+        }
+    }
+}
+
+/// The first of `points` that differs from `end`, if any.
+fn first_other<'a>(end: Point, mut points: impl Iterator<Item = &'a Point>) -> Option<Point> {
+    points.find(|&&p| p != end).copied()
+}
+
+/// Bresenham's line from `start` to `end`, with consecutive pixels on a row merged into runs.
+fn bresenham_runs(start: Pixel, end: Pixel) -> Vec<Run> {
+    let (mut x, mut y) = (start.x as i32, start.y as i32);
+    let (x1, y1) = (end.x as i32, end.y as i32);
+    let dx = (x1 - x).abs();
+    let dy = -(y1 - y).abs();
+    let sx = if x < x1 { 1 } else { -1 };
+    let sy = if y < y1 { 1 } else { -1 };
+    let mut err = dx + dy;
+
+    let mut runs = Vec::with_capacity(dy.unsigned_abs() as usize + 1);
+    let mut row = (y, x, x);
+
+    loop {
+        if y != row.0 {
+            push_row(&mut runs, row);
+            row = (y, x, x);
+        } else {
+            row.1 = row.1.min(x);
+            row.2 = row.2.max(x);
+        }
+
+        if x == x1 && y == y1 {
+            break;
+        }
+
+        let e2 = 2 * err;
+        if e2 >= dy {
+            err += dy;
+            x += sx;
+        }
+        if e2 <= dx {
+            err += dx;
+            y += sy;
+        }
+    }
+
+    push_row(&mut runs, row);
+    runs
+}
+
+/// The span of row `y` covered by [`bresenham_runs`] from `start` to `end`, computed from
+/// the error term instead of walking the line.
+fn bresenham_span(start: Pixel, end: Pixel, y: u16) -> Option<(u16, u16)> {
+    let a = (end.x as i64 - start.x as i64).abs();
+    let b = (end.y as i64 - start.y as i64).abs();
+    let j = if end.y < start.y { start.y as i64 - y as i64 } else { y as i64 - start.y as i64 };
+    if !(0..=b).contains(&j) {
+        return None;
+    }
+
+    // After `i` steps along x and `j` along y the error term is `a (j + 1) - b (i + 1)`,
+    // and the walk leaves row `j` at the first column where twice that is at most `a`.
+    let leave = |j: i64| if j == b { a } else { (a * (2 * j + 1) + 2 * b - 1) / (2 * b) - 1 };
+    let first = match j {
+        0 => 0,
+        _ => {
+            // Leaving the previous row moves diagonally if x steps there as well.
+            let prev = leave(j - 1);
+            prev + (2 * a * j >= b * (2 * prev + 1)) as i64
+        }
+    };
+    let last = leave(j).max(first);
+
+    let column = |i: i64| if start.x < end.x { start.x as i64 + i } else { start.x as i64 - i } as u16;
+    let (x0, x1) = (column(first), column(last));
+    Some((x0.min(x1), x0.max(x1)))
+}
+
+fn push_row(runs: &mut Vec<Run>, (y, x_start, x_end): (i32, i32, i32)) {
+    raster::push_span(runs, y as u16, x_start as u16, x_end as u16);
+}
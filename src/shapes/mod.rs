@@ -4,31 +4,173 @@ pub use crate::shapes::rectangle_outline::RectangleOutline;
 pub use crate::shapes::rectangle::Rectangle;
 pub use crate::shapes::ellipse::Ellipse;
 pub use crate::shapes::ellipse_outline::EllipseOutline;
+pub use crate::shapes::combinators::{Clip, Difference, Intersection, Translate, Union};
+pub use crate::shapes::line::{Line, LineCap};
+pub use crate::shapes::bezier::{CubicBezier, QuadraticBezier};
+pub use crate::shapes::arc::Arc;
+pub use crate::shapes::polyline::Polyline;
+pub use crate::shapes::rotated_rectangle::RotatedRectangle;
+pub use crate::shapes::rotated_ellipse::RotatedEllipse;
+pub use crate::shapes::triangle::{Triangle, TriangleOutline};
+pub use crate::shapes::regular_polygon::{RegularPolygon, RegularPolygonOutline};
+pub use crate::shapes::star::{Star, StarOutline};
+pub use crate::shapes::rounded_rectangle::{RoundedRectangle, RoundedRectangleOutline};
 
+mod arc;
+mod bezier;
+mod combinators;
 mod ellipse;
 mod ellipse_outline;
+mod line;
+mod polyline;
+mod raster;
 mod rectangle;
 mod rectangle_outline;
+mod regular_polygon;
+mod rotated_ellipse;
+mod rotated_rectangle;
+mod rounded_rectangle;
+mod star;
+mod triangle;
 
 /// A geometric shape that can be represented as a set of pixels.
 ///
 /// A `Shape` defines a region in 2D pixel space and provides multiple ways
 /// to interact with it, and convert it to a `PixelSet`.
+///
+/// ## Implementing
+///
+/// A shape only needs to describe itself row by row: its [`bounds`](Shape::bounds) and
+/// the [`row_spans`](Shape::row_spans) it covers on each row. Every other method has a
+/// default built on those two, so rasterization works run by run without visiting
+/// individual pixels. Shapes with a cheaper way to answer a query can still override it.
+///
+/// ## Coordinate Limit
+///
+/// Shapes may extend past the largest `u16` coordinate. They are clipped there: every
+/// method treats the shape as if the pixels beyond 65535 did not exist, and none of them
+/// panic or wrap around.
 pub trait Shape {
+    /// Returns a bounding box `(min_x, min_y, max_x, max_y)` containing every pixel of the
+    /// shape, or `None` if the shape is empty.
+    ///
+    /// The box may be larger than the tightest one when that is cheaper to compute; the
+    /// default methods only visit rows inside it.
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)>;
+
+    /// Returns the pixels of row `y` inside the shape, as inclusive `(x_start, x_end)`
+    /// ranges in increasing order that neither overlap nor touch.
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)>;
+
     /// Generates a `PixelSet` containing all pixels inside the shape.
-    fn set(&self) -> PixelSet;
+    fn set(&self) -> PixelSet {
+        let Some((_, min_y, _, max_y)) = self.bounds() else {
+            return PixelSet::empty();
+        };
+
+        let mut runs = Vec::new();
+        for y in min_y..=max_y {
+            for (x_start, x_end) in self.row_spans(y) {
+                raster::push_span(&mut runs, y, x_start, x_end);
+            }
+        }
+        PixelSet::from_runs(runs)
+    }
 
     /// An iterator of pixels in this shape.
-    fn iter_pixels(&self) -> impl Iterator<Item = Pixel>;
+    fn iter_pixels(&self) -> impl Iterator<Item = Pixel> {
+        let rows = self.bounds().map(|(_, min_y, _, max_y)| min_y..=max_y);
+        rows.into_iter().flatten().flat_map(move |y| {
+            self.row_spans(y)
+                .flat_map(move |(x_start, x_end)| (x_start..=x_end).map(move |x| Pixel::new(x, y)))
+        })
+    }
 
     /// Returns the total number of pixels in the shape.
-    fn len(&self) -> usize;
+    fn len(&self) -> usize {
+        let rows = self.bounds().map(|(_, min_y, _, max_y)| min_y..=max_y);
+        rows.into_iter()
+            .flatten()
+            .flat_map(|y| self.row_spans(y))
+            .map(|(x_start, x_end)| (x_end - x_start) as usize + 1)
+            .sum()
+    }
 
     /// Returns `true` if the shape contains no pixels.
     fn is_empty(&self) -> bool {
-        self.len() == 0
+        let rows = self.bounds().map(|(_, min_y, _, max_y)| min_y..=max_y);
+        !rows.into_iter().flatten().any(|y| self.row_spans(y).next().is_some())
     }
 
     /// Checks if a given pixel is inside the shape.
-    fn has(&self, pixel: Pixel) -> bool;
+    fn has(&self, pixel: Pixel) -> bool {
+        let Some((min_x, min_y, max_x, max_y)) = self.bounds() else {
+            return false;
+        };
+        if pixel.x < min_x || pixel.x > max_x || pixel.y < min_y || pixel.y > max_y {
+            return false;
+        }
+
+        self.row_spans(pixel.y)
+            .take_while(|&(x_start, _)| x_start <= pixel.x)
+            .any(|(_, x_end)| pixel.x <= x_end)
+    }
+
+    /// Returns the signed distance from the point `(x, y)` to the edge of the shape, in
+    /// pixels: negative inside, positive outside and zero on the edge.
+    ///
+    /// Points are in continuous coordinates, where pixel `(x, y)` covers the unit square
+    /// from `(x, y)` to `(x + 1, y + 1)` and is inside the shape when its center is. Empty
+    /// shapes return [`f32::INFINITY`].
+    ///
+    /// The default measures to the edge of the shape's pixels, so the distance follows
+    /// their stair steps. [`Rectangle`], [`Ellipse`] and their outlines use their exact
+    /// geometry instead, which is what gives [`PixelSet::fill_antialiased`] smooth edges.
+    fn signed_distance(&self, x: f32, y: f32) -> f32 {
+        raster::pixel_distance(self, (x as f64, y as f64)) as f32
+    }
+
+    /// Combines this shape with another, covering pixels in either.
+    ///
+    /// Combinators are lazy: [`has`](Shape::has) and [`iter_pixels`](Shape::iter_pixels)
+    /// query the underlying shapes directly, while [`set`](Shape::set) combines their
+    /// runs. Pass shapes by reference to combine them without giving them up.
+    fn union<S: Shape>(self, other: S) -> Union<Self, S>
+    where
+        Self: Sized,
+    {
+        Union::new(self, other)
+    }
+
+    /// Combines this shape with another, covering only pixels in both.
+    fn intersection<S: Shape>(self, other: S) -> Intersection<Self, S>
+    where
+        Self: Sized,
+    {
+        Intersection::new(self, other)
+    }
+
+    /// Removes the pixels of another shape from this one.
+    fn difference<S: Shape>(self, other: S) -> Difference<Self, S>
+    where
+        Self: Sized,
+    {
+        Difference::new(self, other)
+    }
+
+    /// Moves this shape by `(dx, dy)`, dropping pixels that leave the coordinate space.
+    fn translate(self, dx: i32, dy: i32) -> Translate<Self>
+    where
+        Self: Sized,
+    {
+        Translate::new(self, dx, dy)
+    }
+
+    /// Limits this shape to the pixels inside `bounds`, such as an image's extent.
+    fn clip_to(self, bounds: Rectangle) -> Clip<Self>
+    where
+        Self: Sized,
+    {
+        Clip::new(self, bounds)
+    }
 }
\ No newline at end of file
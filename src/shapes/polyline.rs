@@ -0,0 +1,77 @@
+use crate::{Pixel, PixelSet, shapes::Shape};
+use crate::shapes::{Line, LineCap};
+use crate::shapes::line::{Stroke, center};
+use crate::shapes::raster::Point;
+
+/// Represents a connected chain of line segments through a list of pixels.
+///
+/// Each consecutive pair of points is joined like a [`Line`] of the polyline's `width`.
+/// Joints between segments are always rounded, while the two ends of the path are
+/// shaped by `cap`.
+///
+/// ## Edge Cases
+///
+/// - A polyline with no points is empty
+/// - A single point is drawn like a zero-length [`Line`]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Polyline {
+    /// The vertices, in drawing order
+    pub points: Vec<Pixel>,
+    /// Thickness in pixels
+    pub width: u16,
+    /// Shape of the ends and joints of thick polylines
+    pub cap: LineCap,
+}
+
+impl Polyline {
+    /// Creates a single-pixel-wide polyline through the given points.
+    pub fn new(points: Vec<Pixel>) -> Self {
+        Self { points, width: 1, cap: LineCap::Round }
+    }
+
+    /// Returns the polyline with a different thickness.
+    pub fn with_width(self, width: u16) -> Self {
+        Self { width, ..self }
+    }
+
+    /// Returns the polyline with a different end cap.
+    pub fn with_cap(self, cap: LineCap) -> Self {
+        Self { cap, ..self }
+    }
+
+    /// Returns the segments of this polyline as lines, each with the polyline's `width`
+    /// and `cap`.
+    pub fn segments(&self) -> impl Iterator<Item = Line> + '_ {
+        let lone = (self.points.len() == 1).then(|| (self.points[0], self.points[0]));
+        let pairs = self.points.windows(2).map(|pair| (pair[0], pair[1])).chain(lone);
+
+        pairs.map(|(start, end)| Line { start, end, width: self.width, cap: self.cap })
+    }
+
+    fn stroke(&self) -> Stroke {
+        let points: Vec<Point> = self.points.iter().map(|&p| center(p)).collect();
+        Stroke::new(&points, self.width, self.cap)
+    }
+}
+
+impl Shape for Polyline {
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        self.stroke().bounds()
+    }
+
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)> {
+        self.stroke().row_spans(y).into_iter()
+    }
+
+    fn set(&self) -> PixelSet {
+        PixelSet::from_runs(self.stroke().runs())
+    }
+
+    fn iter_pixels(&self) -> impl Iterator<Item = Pixel> {
+        self.set().into_iter()
+    }
+
+    fn len(&self) -> usize {
+        self.set().len()
+    }
+}
@@ -0,0 +1,586 @@
+//! Rasterization helpers shared by the curve and polygon shapes.
+//!
+//! Shapes are described in continuous coordinates where pixel `(x, y)` covers the unit
+//! square from `(x, y)` to `(x + 1, y + 1)`, and is included when its center
+//! `(x + 0.5, y + 0.5)` lies inside the shape. Helpers here compute, per row, the
+//! continuous x-interval a shape covers and convert those intervals into runs.
+
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+use crate::Pixel;
+use crate::set::Run;
+pub(crate) use crate::set::push_span;
+use crate::shapes::Shape;
+
+/// A point in continuous pixel space.
+pub(crate) type Point = (f64, f64);
+
+/// Horizontal and vertical extents of a region, as `(x_range, y_range)`.
+pub(crate) type Extent = ((f64, f64), (f64, f64));
+
+/// Slack for pixel centers landing on a boundary, absorbing rounding in rotated geometry.
+const EPSILON: f64 = 1e-9;
+
+/// Whether pixel centers lying exactly on a shape's boundary are included.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) enum Boundary {
+    /// Centers on any edge are included, like [`Ellipse`](crate::shapes::Ellipse).
+    Closed,
+    /// Centers on the low edge are included and those on the high edge are not, like
+    /// [`Rectangle`](crate::shapes::Rectangle), so adjacent shapes tile without overlap.
+    HalfOpen,
+}
+
+impl Boundary {
+    /// Inclusive range of pixel coordinates whose centers lie within `lo..hi` under this
+    /// boundary rule, clipped to the `u16` coordinate space.
+    pub(crate) fn range(self, lo: f64, hi: f64) -> Option<(u16, u16)> {
+        match self {
+            Boundary::Closed => pixel_range(lo - EPSILON, hi + EPSILON),
+            Boundary::HalfOpen => {
+                let start = (lo - 0.5 - EPSILON).ceil().max(0.0);
+                let end = ((hi - 0.5 - EPSILON).ceil() - 1.0).min(u16::MAX as f64);
+                (start <= end).then_some((start as u16, end as u16))
+            }
+        }
+    }
+}
+
+/// Inclusive range of pixel coordinates whose centers lie within `[lo, hi]`, clipped to
+/// the `u16` coordinate space.
+pub(crate) fn pixel_range(lo: f64, hi: f64) -> Option<(u16, u16)> {
+    let start = (lo - 0.5).ceil().max(0.0);
+    let end = (hi - 0.5).floor().min(u16::MAX as f64);
+    (start <= end).then_some((start as u16, end as u16))
+}
+
+/// The contiguous range of pixels on a row that satisfy `inside`, given a continuous
+/// estimate of it and the columns `limit` it must stay within.
+///
+/// The estimate is widened by a pixel and then trimmed with `inside`, so the exact
+/// predicate rather than floating-point rounding decides pixels on the boundary.
+pub(crate) fn trim_span(
+    estimate: (f64, f64),
+    limit: (u16, u16),
+    inside: impl Fn(u16) -> bool,
+) -> Option<(u16, u16)> {
+    let (start, end) = pixel_range(estimate.0 - 1.0, estimate.1 + 1.0)?;
+    let (mut start, mut end) = (start.max(limit.0) as u32, end.min(limit.1) as u32);
+
+    while start <= end && !inside(start as u16) {
+        start += 1;
+    }
+    while end > start && !inside(end as u16) {
+        end -= 1;
+    }
+    (start <= end).then_some((start as u16, end as u16))
+}
+
+/// The smallest bounding box containing all of `bounds`, or `None` if there are none.
+pub(crate) fn union_bounds(
+    bounds: impl Iterator<Item = (u16, u16, u16, u16)>,
+) -> Option<(u16, u16, u16, u16)> {
+    bounds.reduce(|a, b| (a.0.min(b.0), a.1.min(b.1), a.2.max(b.2), a.3.max(b.3)))
+}
+
+/// A region described analytically, row by row, with a boundary rule.
+///
+/// Shapes built on a region report its [`bounds`](Region::bounds) and
+/// [`row_spans`](Region::row_spans) and leave everything else to the defaults on
+/// [`Shape`](crate::Shape), so runs, membership and pixel counts always agree.
+pub(crate) struct Region<F> {
+    pub(crate) boundary: Boundary,
+    /// Horizontal extent of the region.
+    pub(crate) x_extent: (f64, f64),
+    /// Vertical extent of the region.
+    pub(crate) y_extent: (f64, f64),
+    /// Continuous x-intervals covered at a given height, in any order and possibly
+    /// overlapping.
+    pub(crate) row_intervals: F,
+}
+
+impl<F: Fn(f64) -> Vec<(f64, f64)>> Region<F> {
+    /// Bounding box of the pixels the region may cover.
+    pub(crate) fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        let (min_x, max_x) = self.boundary.range(self.x_extent.0, self.x_extent.1)?;
+        let (min_y, max_y) = self.boundary.range(self.y_extent.0, self.y_extent.1)?;
+        Some((min_x, min_y, max_x, max_y))
+    }
+
+    /// Sorted, disjoint and non-adjacent ranges of pixel x-coordinates covered on row `y`.
+    pub(crate) fn row_spans(&self, y: u16) -> Vec<(u16, u16)> {
+        let Some((y_start, y_end)) = self.boundary.range(self.y_extent.0, self.y_extent.1) else {
+            return Vec::new();
+        };
+        if y < y_start || y > y_end {
+            return Vec::new();
+        }
+
+        let spans = (self.row_intervals)(y as f64 + 0.5)
+            .into_iter()
+            .filter_map(|(lo, hi)| self.boundary.range(lo, hi))
+            .collect();
+        merge_spans(spans)
+    }
+
+    /// Runs of the region, in canonical order.
+    pub(crate) fn runs(&self) -> Vec<Run> {
+        let mut runs = Vec::new();
+        if let Some((_, min_y, _, max_y)) = self.bounds() {
+            for y in min_y..=max_y {
+                for (x_start, x_end) in self.row_spans(y) {
+                    push_span(&mut runs, y, x_start, x_end);
+                }
+            }
+        }
+        runs
+    }
+}
+
+/// Sorts pixel spans and merges those that overlap or touch.
+pub(crate) fn merge_spans(mut spans: Vec<(u16, u16)>) -> Vec<(u16, u16)> {
+    spans.sort_unstable();
+
+    let mut merged: Vec<(u16, u16)> = Vec::with_capacity(spans.len());
+    for (start, end) in spans {
+        match merged.last_mut() {
+            Some(last) if start as u32 <= last.1 as u32 + 1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// A filled polygon, or the stroke along the inside of its boundary, built from convex
+/// pieces.
+pub(crate) struct Polygon {
+    /// Convex pieces whose union is the polygon.
+    pub(crate) pieces: Vec<Vec<Point>>,
+    /// Vertices of the polygon's boundary, in order.
+    pub(crate) boundary: Vec<Point>,
+    /// Maximum distance from the boundary of included points, or `None` if filled.
+    pub(crate) stroke: Option<f64>,
+}
+
+impl Polygon {
+    /// A filled convex polygon.
+    pub(crate) fn convex(vertices: Vec<Point>) -> Self {
+        Self { pieces: vec![vertices.clone()], boundary: vertices, stroke: None }
+    }
+
+    /// Keeps only the part of the polygon within a stroke of `stroke` pixels of its
+    /// boundary, like a line `2 * stroke - 1` pixels wide along each edge.
+    pub(crate) fn outline(self, stroke: u16) -> Self {
+        if stroke == 0 {
+            return Self { pieces: Vec::new(), ..self };
+        }
+        Self { stroke: Some(stroke as f64 - 0.5), ..self }
+    }
+
+    pub(crate) fn region(&self) -> Region<impl Fn(f64) -> Vec<(f64, f64)> + '_> {
+        let (x_extent, y_extent) = points_extent(&self.boundary);
+
+        Region {
+            boundary: Boundary::Closed,
+            x_extent,
+            y_extent,
+            row_intervals: move |y| self.row_intervals(y),
+        }
+    }
+
+    pub(crate) fn row_intervals(&self, y: f64) -> Vec<(f64, f64)> {
+        let fill = self.pieces.iter().filter_map(|piece| convex_polygon_interval(piece, y));
+        let Some(radius) = self.stroke else {
+            return fill.collect();
+        };
+
+        let n = self.boundary.len();
+        let edges = (0..n).filter_map(|i| {
+            capsule_interval(self.boundary[i], self.boundary[(i + 1) % n], radius, y)
+        });
+        intersect_all(&merge(fill.collect()), &merge(edges.collect()))
+    }
+}
+
+/// The corners of a regular polygon, computed on demand so that polygons with many sides
+/// cost no more than a few corners per row.
+///
+/// Corner `i` lies `radius` away from `origin`, at `2π * i / count` counterclockwise from
+/// straight up, turned further by `rotation`. Indices wrap around, so any `i64` names a
+/// corner.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct RegularCorners {
+    origin: Point,
+    radius: f64,
+    count: usize,
+    rotation: f64,
+}
+
+impl RegularCorners {
+    pub(crate) fn new(origin: Point, radius: f64, count: usize, rotation: f64) -> Self {
+        Self { origin, radius, count, rotation: rotation.rem_euclid(TAU) }
+    }
+
+    pub(crate) fn corner(&self, i: i64) -> Point {
+        let angle = FRAC_PI_2 + self.rotation + TAU * i as f64 / self.count as f64;
+        (self.origin.0 + self.radius * angle.cos(), self.origin.1 - self.radius * angle.sin())
+    }
+
+    /// Index of the corner at `angle`, counterclockwise from the positive x-axis, as a
+    /// fraction between the corners on either side of it.
+    pub(crate) fn position(&self, angle: f64) -> f64 {
+        (angle - FRAC_PI_2 - self.rotation) * self.count as f64 / TAU
+    }
+
+    /// The corners closest to the direction at `angle`, one of which lies farthest along it.
+    fn around(&self, angle: f64) -> impl Iterator<Item = i64> {
+        let i = self.position(angle).round() as i64;
+        i - 1..=i + 1
+    }
+
+    /// Horizontal and vertical extents of the corners.
+    pub(crate) fn extent(&self) -> Extent {
+        let corners: Vec<_> = [0.0, FRAC_PI_2, PI, -FRAC_PI_2]
+            .into_iter()
+            .flat_map(|angle| self.around(angle))
+            .map(|i| self.corner(i))
+            .collect();
+        points_extent(&corners)
+    }
+
+    /// The polygon whose edges lie `distance` inside these, or `None` if it vanishes.
+    pub(crate) fn inset(&self, distance: f64) -> Option<Self> {
+        let radius = self.radius - distance / (PI / self.count as f64).cos();
+        (radius > 0.0).then_some(Self { radius, ..*self })
+    }
+
+    /// Row interval of the filled polygon on the horizontal line at height `y`.
+    ///
+    /// Heights change monotonically along each side between the lowest and highest
+    /// corners, so the edges crossing `y` are found by bisection.
+    pub(crate) fn interval(&self, y: f64) -> Option<(f64, f64)> {
+        let height = |i: i64| self.corner(i).1;
+        let bottom = self.around(-FRAC_PI_2).max_by(|&a, &b| height(a).total_cmp(&height(b)))?;
+        let top = self.around(FRAC_PI_2).min_by(|&a, &b| height(a).total_cmp(&height(b)))?;
+        let count = self.count as i64;
+        let top = bottom + (top - bottom).rem_euclid(count);
+
+        let (high, low) = (height(top), height(bottom));
+        if !(high - EPSILON <= y && y <= low + EPSILON) {
+            return None;
+        }
+        let y = y.max(high).min(low);
+
+        // Counterclockwise from the bottom corner, the right side rises to the top one.
+        let right = self.crossing(bottom, top, y, |h| h <= y, f64::max);
+        let left = self.crossing(top, bottom + count, y, |h| h >= y, f64::min);
+        Some((left, right))
+    }
+
+    /// Where the side from corner `from` to corner `to` meets height `y`, given which
+    /// heights lie `past` it, taking `pick` of the two ends of a horizontal edge.
+    fn crossing(
+        &self,
+        from: i64,
+        to: i64,
+        y: f64,
+        past: impl Fn(f64) -> bool,
+        pick: fn(f64, f64) -> f64,
+    ) -> f64 {
+        let (mut lo, mut hi) = (from, to);
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            if past(self.corner(mid).1) { hi = mid } else { lo = mid }
+        }
+
+        let (a, b) = (self.corner(lo), self.corner(hi));
+        if a.1 == b.1 {
+            return pick(a.0, b.0);
+        }
+        let t = ((y - a.1) / (b.1 - a.1)).clamp(0.0, 1.0);
+        a.0 + t * (b.0 - a.0)
+    }
+}
+
+/// Row interval of the convex polygon with the given vertices, in either winding order,
+/// on the horizontal line at height `y`. Polygons without area are empty.
+pub(crate) fn convex_polygon_interval(vertices: &[Point], y: f64) -> Option<(f64, f64)> {
+    let n = vertices.len();
+    let area: f64 = (0..n)
+        .map(|i| {
+            let (p, q) = (vertices[i], vertices[(i + 1) % n]);
+            p.0 * q.1 - q.0 * p.1
+        })
+        .sum();
+    if area.abs() < EPSILON {
+        return None;
+    }
+    let sign = area.signum();
+
+    // Each edge keeps the half-plane on the polygon's side: cross(q - p, x - p) >= 0.
+    let mut interval = (f64::NEG_INFINITY, f64::INFINITY);
+    for i in 0..n {
+        let (p, q) = (vertices[i], vertices[(i + 1) % n]);
+        let slope = -(q.1 - p.1) * sign;
+        let offset = ((q.0 - p.0) * (y - p.1) + (q.1 - p.1) * p.0) * sign;
+        let half = linear_interval(slope, offset, 0.0, f64::INFINITY)?;
+        interval = intersect(interval, half)?;
+    }
+    Some(interval)
+}
+
+/// Sorts intervals and merges those that overlap or touch.
+fn merge(mut intervals: Vec<(f64, f64)>) -> Vec<(f64, f64)> {
+    intervals.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut merged: Vec<(f64, f64)> = Vec::with_capacity(intervals.len());
+    for (lo, hi) in intervals {
+        match merged.last_mut() {
+            Some(last) if lo <= last.1 => last.1 = last.1.max(hi),
+            _ => merged.push((lo, hi)),
+        }
+    }
+    merged
+}
+
+/// Intersection of two sorted lists of disjoint intervals.
+fn intersect_all(a: &[(f64, f64)], b: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let (mut i, mut j) = (0, 0);
+    let mut out = Vec::new();
+    while i < a.len() && j < b.len() {
+        if let Some(both) = intersect(a[i], b[j]) {
+            out.push(both);
+        }
+        if a[i].1 < b[j].1 { i += 1 } else { j += 1 }
+    }
+    out
+}
+
+/// Row interval of the points within `radius` of the segment from `a` to `b` (a capsule),
+/// on the horizontal line at height `y`.
+pub(crate) fn capsule_interval(a: Point, b: Point, radius: f64, y: f64) -> Option<(f64, f64)> {
+    let mut interval = None;
+
+    for (cx, cy) in [a, b] {
+        let dy = y - cy;
+        if dy.abs() <= radius {
+            let half = (radius * radius - dy * dy).sqrt();
+            interval = union(interval, Some((cx - half, cx + half)));
+        }
+    }
+
+    let length = (b.0 - a.0).hypot(b.1 - a.1);
+    if length > 0.0 {
+        interval = union(interval, band_interval(a, b, (0.0, length), radius, y));
+    }
+
+    interval
+}
+
+/// Row interval of the rectangle around the line through `a` and `b`, extending `radius`
+/// to either side and spanning distances `along` from `a` in the direction of `b`, on the
+/// horizontal line at height `y`.
+///
+/// A zero-length segment is treated as pointing along the x-axis.
+pub(crate) fn band_interval(
+    a: Point,
+    b: Point,
+    along: (f64, f64),
+    radius: f64,
+    y: f64,
+) -> Option<(f64, f64)> {
+    let (dx, dy) = unit(a, b);
+    let (nx, ny) = (-dy, dx);
+
+    // Projections onto the segment direction and its normal, each linear in x.
+    let t = linear_interval(dx, dy * (y - a.1) - dx * a.0, along.0, along.1)?;
+    let s = linear_interval(nx, ny * (y - a.1) - nx * a.0, -radius, radius)?;
+    intersect(t, s)
+}
+
+/// Row interval of the ellipse centered at `center` with semi-axes `radii`, the first
+/// of which lies along the unit vector `axis`, on the horizontal line at height `y`.
+pub(crate) fn ellipse_interval(
+    center: Point,
+    radii: (f64, f64),
+    axis: (f64, f64),
+    y: f64,
+) -> Option<(f64, f64)> {
+    let (a, b) = radii;
+    let (dx, dy) = axis;
+    if a <= 0.0 || b <= 0.0 {
+        return None;
+    }
+
+    // With u = X·axis and v = X·normal for X relative to the center, the ellipse
+    // u²/a² + v²/b² <= 1 becomes a quadratic in the row offset x.
+    let (ia, ib) = (1.0 / (a * a), 1.0 / (b * b));
+    let oy = y - center.1;
+    let qa = dx * dx * ia + dy * dy * ib;
+    let qb = 2.0 * oy * dx * dy * (ia - ib);
+    let qc = oy * oy * (dy * dy * ia + dx * dx * ib) - 1.0;
+
+    let discriminant = qb * qb - 4.0 * qa * qc;
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    let root = discriminant.sqrt();
+    Some((center.0 + (-qb - root) / (2.0 * qa), center.0 + (-qb + root) / (2.0 * qa)))
+}
+
+/// Horizontal and vertical extents of the ellipse described by [`ellipse_interval`].
+pub(crate) fn ellipse_extent(center: Point, radii: (f64, f64), axis: (f64, f64)) -> Extent {
+    let half_width = (radii.0 * axis.0).hypot(radii.1 * axis.1);
+    let half_height = (radii.0 * axis.1).hypot(radii.1 * axis.0);
+    (
+        (center.0 - half_width, center.0 + half_width),
+        (center.1 - half_height, center.1 + half_height),
+    )
+}
+
+/// Horizontal and vertical extents of the capsule around the segment from `a` to `b`.
+pub(crate) fn capsule_extent(a: Point, b: Point, radius: f64) -> Extent {
+    (
+        (a.0.min(b.0) - radius, a.0.max(b.0) + radius),
+        (a.1.min(b.1) - radius, a.1.max(b.1) + radius),
+    )
+}
+
+/// Horizontal and vertical extents of the rectangle described by [`band_interval`].
+pub(crate) fn band_extent(a: Point, b: Point, along: (f64, f64), radius: f64) -> Extent {
+    let (dx, dy) = unit(a, b);
+
+    // Corners: `t` along the segment, `s` along its normal `(-dy, dx)`.
+    let corners = [(along.0, -radius), (along.0, radius), (along.1, -radius), (along.1, radius)]
+        .map(|(t, s)| (a.0 + t * dx - s * dy, a.1 + t * dy + s * dx));
+    points_extent(&corners)
+}
+
+/// Horizontal and vertical extents of a set of points.
+pub(crate) fn points_extent(points: &[Point]) -> Extent {
+    points.iter().fold(
+        ((f64::MAX, f64::MIN), (f64::MAX, f64::MIN)),
+        |((x_lo, x_hi), (y_lo, y_hi)), p| ((x_lo.min(p.0), x_hi.max(p.0)), (y_lo.min(p.1), y_hi.max(p.1))),
+    )
+}
+
+/// Signed distance from `point` to the edge of the box spanning `extent`, negative inside.
+pub(crate) fn box_distance(((x0, x1), (y0, y1)): Extent, point: Point) -> f64 {
+    let dx = (x0 - point.0).max(point.0 - x1);
+    let dy = (y0 - point.1).max(point.1 - y1);
+    dx.max(0.0).hypot(dy.max(0.0)) + dx.max(dy).min(0.0)
+}
+
+/// Signed distance from `point` to the edge of the axis-aligned ellipse centered at
+/// `center` with semi-axes `radii`, negative inside.
+///
+/// The distance is exact for circles. For other ellipses it is estimated from the
+/// gradient of the ellipse equation, which is accurate near the edge and zero on it.
+pub(crate) fn ellipse_distance(center: Point, radii: (f64, f64), point: Point) -> f64 {
+    let (dx, dy) = (point.0 - center.0, point.1 - center.1);
+    let k0 = (dx / radii.0).hypot(dy / radii.1);
+    let k1 = (dx / (radii.0 * radii.0)).hypot(dy / (radii.1 * radii.1));
+    if k1 == 0.0 {
+        return -radii.0.min(radii.1);
+    }
+    k0 * (k0 - 1.0) / k1
+}
+
+/// Signed distance from `point` to the edge of a shape's pixels, each taken as a unit
+/// square, negative inside.
+///
+/// Outside the shape this is the distance to its nearest pixel; inside, the distance to
+/// the nearest pixel outside it. Rows are visited outward from `point` until they are
+/// farther away than the best distance found.
+pub(crate) fn pixel_distance<S: Shape + ?Sized>(shape: &S, (x, y): Point) -> f64 {
+    let Some((_, min_y, _, max_y)) = shape.bounds() else {
+        return f64::INFINITY;
+    };
+
+    let in_range = (0.0..65536.0).contains(&x) && (0.0..65536.0).contains(&y);
+    let inside = in_range && shape.has(Pixel::new(x as u16, y as u16));
+
+    // From inside, the rows just beyond the bounds are entirely outside the shape.
+    let (min_y, max_y) = (min_y as i64, max_y as i64);
+    let (lo, hi) = if inside { (min_y - 1, max_y + 1) } else { (min_y, max_y) };
+
+    // Intervals of the row to measure to: the shape's pixels, or the gaps between them.
+    let targets = |row: i64| -> Vec<(f64, f64)> {
+        if row < min_y || row > max_y {
+            return vec![(f64::NEG_INFINITY, f64::INFINITY)];
+        }
+        let spans = shape.row_spans(row as u16).map(|(start, end)| (start as f64, end as f64 + 1.0));
+        if !inside {
+            return spans.collect();
+        }
+
+        let mut gaps = Vec::new();
+        let mut gap_start = f64::NEG_INFINITY;
+        for (start, end) in spans {
+            gaps.push((gap_start, start));
+            gap_start = end;
+        }
+        gaps.push((gap_start, f64::INFINITY));
+        gaps
+    };
+
+    let mut best = f64::INFINITY;
+    let mut visit = |row: i64| {
+        let dy = (row as f64 - y).max(y - (row as f64 + 1.0)).max(0.0);
+        if dy >= best {
+            return false;
+        }
+        for (start, end) in targets(row) {
+            let dx = (start - x).max(x - end).max(0.0);
+            best = best.min(dx.hypot(dy));
+        }
+        true
+    };
+
+    let start = (y.floor() as i64).clamp(lo, hi);
+    for row in start..=hi {
+        if !visit(row) {
+            break;
+        }
+    }
+    for row in (lo..start).rev() {
+        if !visit(row) {
+            break;
+        }
+    }
+
+    if inside { -best } else { best }
+}
+
+/// Unit vector from `a` to `b`, or the x-axis if they coincide.
+fn unit(a: Point, b: Point) -> (f64, f64) {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let length = dx.hypot(dy);
+    if length > 0.0 { (dx / length, dy / length) } else { (1.0, 0.0) }
+}
+
+/// Solutions `x` of `min <= slope * x + offset <= max`.
+fn linear_interval(slope: f64, offset: f64, min: f64, max: f64) -> Option<(f64, f64)> {
+    if slope.abs() < 1e-12 {
+        let inside = min - EPSILON <= offset && offset <= max + EPSILON;
+        return inside.then_some((f64::NEG_INFINITY, f64::INFINITY));
+    }
+
+    let (lo, hi) = ((min - offset) / slope, (max - offset) / slope);
+    Some((lo.min(hi), lo.max(hi)))
+}
+
+fn intersect(a: (f64, f64), b: (f64, f64)) -> Option<(f64, f64)> {
+    let (lo, hi) = (a.0.max(b.0), a.1.min(b.1));
+    (lo <= hi).then_some((lo, hi))
+}
+
+/// Hull of two intervals that are known to overlap, as parts of one convex region.
+fn union(a: Option<(f64, f64)>, b: Option<(f64, f64)>) -> Option<(f64, f64)> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some((a.0.min(b.0), a.1.max(b.1))),
+        (a, None) => a,
+        (None, b) => b,
+    }
+}
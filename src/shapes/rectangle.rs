@@ -1,5 +1,6 @@
 use crate::{Pixel, PixelSet, shapes::Shape};
 use crate::set::Run;
+use crate::shapes::raster;
 
 /// Represents an axis-aligned filled rectangle with its pixels.
 ///
@@ -11,6 +12,7 @@ use crate::set::Run;
 /// - A rectangle with zero width or height contains no pixels
 /// - Pixel coordinates are inclusive of the starting corner and exclusive of the far edge
 /// - The shape is immutable and all coordinates use unsigned 16-bit integers
+/// - A rectangle extending past coordinate 65535 is clipped there
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub struct Rectangle {
     /// The x-coordinate of the top-left corner of the box.
@@ -36,34 +38,63 @@ impl Rectangle {
 }
 
 impl Shape for Rectangle {
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        if self.width == 0 || self.height == 0 {
+            return None;
+        }
+
+        let max_x = self.x.saturating_add(self.width - 1);
+        let max_y = self.y.saturating_add(self.height - 1);
+        Some((self.x, self.y, max_x, max_y))
+    }
+
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)> {
+        self.bounds()
+            .filter(|&(_, min_y, _, max_y)| min_y <= y && y <= max_y)
+            .map(|(min_x, _, max_x, _)| (min_x, max_x))
+            .into_iter()
+    }
+
     fn set(&self) -> PixelSet {
-        let mut runs = Vec::with_capacity(self.height as usize);
+        let Some((min_x, min_y, max_x, max_y)) = self.bounds() else {
+            return PixelSet::empty();
+        };
 
-        for y in self.y..(self.y + self.height) {
-            runs.push(Run {
-                y,
-                x_start: self.x,
-                length: self.width,
-            });
-        }
+        // Clipped at the coordinate limit, so the width always fits in a run.
+        let length = max_x - min_x + 1;
+        let runs = (min_y..=max_y)
+            .map(|y| Run { y, x_start: min_x, length })
+            .collect();
 
         PixelSet::from_runs_unchecked(runs)
     }
 
     fn iter_pixels(&self) -> impl Iterator<Item = Pixel> {
-        (self.y..self.y + self.height).flat_map(move |y| {
-            (self.x..self.x + self.width).map(move |x| Pixel::new(x, y))
+        self.bounds().into_iter().flat_map(|(min_x, min_y, max_x, max_y)| {
+            (min_y..=max_y).flat_map(move |y| (min_x..=max_x).map(move |x| Pixel::new(x, y)))
         })
     }
 
     fn len(&self) -> usize {
-        (self.width * self.height) as usize
+        self.bounds().map_or(0, |(min_x, min_y, max_x, max_y)| {
+            ((max_x - min_x) as usize + 1) * ((max_y - min_y) as usize + 1)
+        })
     }
 
     fn has(&self, pixel: Pixel) -> bool {
         pixel.x >= self.x
-            && pixel.x < self.x + self.width
+            && pixel.x - self.x < self.width
             && pixel.y >= self.y
-            && pixel.y < self.y + self.height
+            && pixel.y - self.y < self.height
+    }
+
+    fn signed_distance(&self, x: f32, y: f32) -> f32 {
+        if self.width == 0 || self.height == 0 {
+            return f32::INFINITY;
+        }
+
+        let (x0, y0) = (self.x as f64, self.y as f64);
+        let extent = ((x0, x0 + self.width as f64), (y0, y0 + self.height as f64));
+        raster::box_distance(extent, (x as f64, y as f64)) as f32
     }
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::{Pixel, PixelSet, shapes::Shape};
+use crate::{Pixel, shapes::{Rectangle, Shape}};
 
 /// Represents a rectangular border with adjustable stroke width.
 ///
@@ -31,64 +31,66 @@ pub struct RectangleOutline {
     pub stroke: u16,
 }
 
-impl Shape for RectangleOutline {
-    fn has(&self, pixel: Pixel) -> bool {
-        let Pixel { x, y } = pixel;
-
-        let outer =
-            x >= self.x &&
-            x < self.x + self.width &&
-            y >= self.y &&
-            y < self.y + self.height;
-
-        if !outer {
-            return false;
-        }
+impl RectangleOutline {
+    fn outer(&self) -> Rectangle {
+        Rectangle { x: self.x, y: self.y, width: self.width, height: self.height }
+    }
 
-        let inner_x = self.x + self.stroke;
-        let inner_y = self.y + self.stroke;
-        let inner_w = self.width.saturating_sub(self.stroke * 2);
-        let inner_h = self.height.saturating_sub(self.stroke * 2);
+    /// The unfilled middle, or `None` if the stroke covers the whole rectangle or the
+    /// middle starts beyond the coordinate limit.
+    fn inner(&self) -> Option<Rectangle> {
+        let inset = self.stroke as u32 * 2;
+        let inner_w = (self.width as u32).saturating_sub(inset) as u16;
+        let inner_h = (self.height as u32).saturating_sub(inset) as u16;
+        let x = self.x.checked_add(self.stroke)?;
+        let y = self.y.checked_add(self.stroke)?;
 
-        let inner =
-            inner_w > 0 &&
-            inner_h > 0 &&
-            x >= inner_x &&
-            x < inner_x + inner_w &&
-            y >= inner_y &&
-            y < inner_y + inner_h;
+        (inner_w > 0 && inner_h > 0).then_some(Rectangle { x, y, width: inner_w, height: inner_h })
+    }
+}
 
-        !inner
+impl Shape for RectangleOutline {
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        if self.stroke == 0 {
+            return None;
+        }
+        self.outer().bounds()
     }
 
-    fn iter_pixels(&self) -> impl Iterator<Item = Pixel> {
-        let x0 = self.x;
-        let y0 = self.y;
-        let x1 = self.x + self.width;
-        let y1 = self.y + self.height;
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)> {
+        let outer = self.outer().row_spans(y).next().filter(|_| self.stroke > 0);
+        let inner = self.inner().and_then(|inner| inner.row_spans(y).next());
 
-        (y0..y1).flat_map(move |y| {
-            (x0..x1).filter_map(move |x| {
-                let p = Pixel::new(x, y);
-                self.has(p).then_some(p)
-            })
-        })
+        let (left, right) = match (outer, inner) {
+            (Some((x_start, x_end)), Some((inner_start, inner_end))) => {
+                // Both are clipped at the coordinate limit, where the right side vanishes.
+                let right = (inner_end < x_end).then(|| (inner_end + 1, x_end));
+                (Some((x_start, inner_start - 1)), right)
+            }
+            (outer, _) => (outer, None),
+        };
+        left.into_iter().chain(right)
     }
 
-    fn set(&self) -> PixelSet {
-        let mut pixels = Vec::with_capacity(self.len());
-        pixels.extend(self.iter_pixels());
-        PixelSet::new_unchecked(pixels)
+    fn has(&self, pixel: Pixel) -> bool {
+        self.stroke > 0
+            && self.outer().has(pixel)
+            && !self.inner().is_some_and(|inner| inner.has(pixel))
     }
 
     fn len(&self) -> usize {
-        let outer = self.width as usize * self.height as usize;
-
-        let inner_w = self.width.saturating_sub(self.stroke * 2) as usize;
-        let inner_h = self.height.saturating_sub(self.stroke * 2) as usize;
+        if self.stroke == 0 {
+            return 0;
+        }
+        self.outer().len() - self.inner().map_or(0, |inner| inner.len())
+    }
 
-        let inner = inner_w * inner_h;
+    fn signed_distance(&self, x: f32, y: f32) -> f32 {
+        if self.stroke == 0 {
+            return f32::INFINITY;
+        }
 
-        outer - inner
+        let outer = self.outer().signed_distance(x, y);
+        self.inner().map_or(outer, |inner| outer.max(-inner.signed_distance(x, y)))
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,122 @@
+use crate::{Pixel, shapes::Shape};
+use crate::shapes::line::center;
+use crate::shapes::raster::{Boundary, Region, RegularCorners, points_extent};
+
+/// Represents a filled regular polygon, such as a hexagon, around a center pixel.
+///
+/// The polygon's `sides` corners lie on a circle of `radius` pixels around the center of
+/// `center`. With a `rotation` of 0 the first corner points straight up; positive
+/// rotations turn the polygon counterclockwise, in radians, like
+/// [`Direction::from_angle`](crate::Direction::from_angle).
+///
+/// Pixels are included if their center lies inside the polygon or on its edges. Spans
+/// are computed analytically per row.
+///
+/// ## Edge Cases
+///
+/// - Fewer than 3 sides or a radius of 0 produces an empty polygon
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct RegularPolygon {
+    /// Center of the polygon
+    pub center: Pixel,
+    /// Distance from the center to each corner, in pixels
+    pub radius: u16,
+    /// Number of sides
+    pub sides: u16,
+    /// Counterclockwise rotation in radians
+    pub rotation: f32,
+}
+
+/// Represents a regular polygon border with adjustable stroke width.
+///
+/// ## Stroke Behavior
+///
+/// The `stroke` parameter defines the thickness of the border in pixels, measured inward
+/// from the edges of the corresponding [`RegularPolygon`], with the same rule as
+/// [`TriangleOutline`](crate::shapes::TriangleOutline).
+///
+/// ## Edge Cases
+///
+/// - If the stroke reaches the center, the entire polygon is included
+/// - A stroke of 0 produces an empty outline
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct RegularPolygonOutline {
+    /// Center of the polygon
+    pub center: Pixel,
+    /// Distance from the center to each corner, in pixels
+    pub radius: u16,
+    /// Number of sides
+    pub sides: u16,
+    /// Counterclockwise rotation in radians
+    pub rotation: f32,
+    /// Thickness of the outline in pixels
+    pub stroke: u16,
+}
+
+impl RegularPolygon {
+    fn corners(&self) -> Option<RegularCorners> {
+        if self.sides < 3 || self.radius == 0 {
+            return None;
+        }
+        let (origin, radius) = (center(self.center), self.radius as f64);
+        Some(RegularCorners::new(origin, radius, self.sides as usize, self.rotation as f64))
+    }
+}
+
+impl RegularPolygonOutline {
+    fn corners(&self) -> Option<RegularCorners> {
+        let Self { center, radius, sides, rotation, stroke } = *self;
+        if stroke == 0 {
+            return None;
+        }
+        RegularPolygon { center, radius, sides, rotation }.corners()
+    }
+}
+
+/// The region inside `corners`, keeping only the points within `stroke` of an edge when
+/// one is given.
+///
+/// Points farther than the stroke from every edge form a smaller copy of the polygon, so
+/// each row is the polygon's interval with that copy's interval cut out of it.
+fn region(
+    corners: Option<RegularCorners>,
+    stroke: Option<f64>,
+) -> Region<impl Fn(f64) -> Vec<(f64, f64)>> {
+    let (x_extent, y_extent) = corners.map_or_else(|| points_extent(&[]), |c| c.extent());
+    let hole = corners.zip(stroke).and_then(|(c, stroke)| c.inset(stroke));
+
+    Region {
+        boundary: Boundary::Closed,
+        x_extent,
+        y_extent,
+        row_intervals: move |y| {
+            let Some((lo, hi)) = corners.and_then(|c| c.interval(y)) else {
+                return Vec::new();
+            };
+            match hole.and_then(|h| h.interval(y)) {
+                Some((inner_lo, inner_hi)) => vec![(lo, inner_lo), (inner_hi, hi)],
+                None => vec![(lo, hi)],
+            }
+        },
+    }
+}
+
+impl Shape for RegularPolygon {
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        region(self.corners(), None).bounds()
+    }
+
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)> {
+        region(self.corners(), None).row_spans(y).into_iter()
+    }
+}
+
+impl Shape for RegularPolygonOutline {
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        region(self.corners(), Some(self.stroke as f64 - 0.5)).bounds()
+    }
+
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)> {
+        region(self.corners(), Some(self.stroke as f64 - 0.5)).row_spans(y).into_iter()
+    }
+}
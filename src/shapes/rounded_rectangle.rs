@@ -0,0 +1,152 @@
+use crate::shapes::Shape;
+use crate::shapes::raster::{Boundary, Region};
+
+/// Represents an axis-aligned filled rectangle with rounded corners.
+///
+/// The rectangle covers the same box as a [`Rectangle`] with the same `x`, `y`, `width`
+/// and `height`, with each corner replaced by a quarter circle of `radius` pixels.
+/// Pixels are included if their center lies inside the rounded box, following the same
+/// edge rule as [`Rectangle`], and spans are computed analytically per row.
+///
+/// ## Edge Cases
+///
+/// - A radius of 0 gives exactly the corresponding [`Rectangle`]
+/// - The radius is limited to half the shorter side, which gives a stadium shape
+/// - Zero width or height results in an empty rectangle
+///
+/// [`Rectangle`]: crate::shapes::Rectangle
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct RoundedRectangle {
+    /// The x-coordinate of the top-left corner of the box.
+    pub x: u16,
+    /// The y-coordinate of the top-left corner of the box.
+    pub y: u16,
+    /// The height of the box in pixels.
+    pub height: u16,
+    /// The width of the box in pixels.
+    pub width: u16,
+    /// Radius of the rounded corners in pixels.
+    pub radius: u16,
+}
+
+/// Represents a rounded rectangle border with adjustable stroke width.
+///
+/// ## Stroke Behavior
+///
+/// Like [`RectangleOutline`], the `stroke` parameter defines the thickness of the border
+/// in pixels, measured inward from the outer edges. A pixel is included if it's:
+/// - Within the outer [`RoundedRectangle`], AND
+/// - Not within the inner one, which is shrunk by `stroke` on all sides and has its
+///   corner radius reduced by `stroke`
+///
+/// ## Edge Cases
+///
+/// - If `stroke * 2 >= width` or `stroke * 2 >= height`, the inner rectangle vanishes
+///   and the entire outer rectangle is included
+/// - A stroke of 0 produces an empty outline
+///
+/// [`RectangleOutline`]: crate::shapes::RectangleOutline
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct RoundedRectangleOutline {
+    /// The x-coordinate of the top-left corner of the box.
+    pub x: u16,
+    /// The y-coordinate of the top-left corner of the box.
+    pub y: u16,
+    /// The height of the box in pixels.
+    pub height: u16,
+    /// The width of the box in pixels.
+    pub width: u16,
+    /// Radius of the outer rounded corners in pixels.
+    pub radius: u16,
+    /// Thickness of the outline in pixels
+    pub stroke: u16,
+}
+
+impl RoundedRectangle {
+    /// Continuous x-interval covered at height `y`.
+    fn interval(&self, y: f64) -> Option<(f64, f64)> {
+        let (x0, y0) = (self.x as f64, self.y as f64);
+        let (width, height) = (self.width as f64, self.height as f64);
+        if width == 0.0 || height == 0.0 || y < y0 || y > y0 + height {
+            return None;
+        }
+
+        let radius = (self.radius as f64).min(width / 2.0).min(height / 2.0);
+        let dy = (y0 + radius - y).max(y - (y0 + height - radius)).max(0.0);
+        let inset = radius - (radius * radius - dy * dy).max(0.0).sqrt();
+        Some((x0 + inset, x0 + width - inset))
+    }
+
+    fn region(&self) -> Region<impl Fn(f64) -> Vec<(f64, f64)> + '_> {
+        Region {
+            boundary: Boundary::HalfOpen,
+            x_extent: (self.x as f64, self.x as f64 + self.width as f64),
+            y_extent: (self.y as f64, self.y as f64 + self.height as f64),
+            row_intervals: move |y| self.interval(y).into_iter().collect(),
+        }
+    }
+}
+
+impl RoundedRectangleOutline {
+    fn outer(&self) -> RoundedRectangle {
+        let Self { x, y, width, height, radius, .. } = *self;
+        RoundedRectangle { x, y, width, height, radius }
+    }
+
+    /// The rounded rectangle left unfilled, or `None` if the stroke fills it entirely.
+    fn inner(&self) -> Option<RoundedRectangle> {
+        let stroke = self.stroke as u32;
+        if stroke * 2 >= self.width as u32 || stroke * 2 >= self.height as u32 {
+            return None;
+        }
+
+        Some(RoundedRectangle {
+            // Beyond the coordinate limit, nothing visible is left to hollow out.
+            x: self.x.checked_add(self.stroke)?,
+            y: self.y.checked_add(self.stroke)?,
+            width: self.width - self.stroke * 2,
+            height: self.height - self.stroke * 2,
+            radius: self.radius.saturating_sub(self.stroke),
+        })
+    }
+
+    fn region(&self) -> Region<impl Fn(f64) -> Vec<(f64, f64)>> {
+        let (outer, inner) = (self.outer(), self.inner());
+        let stroke = self.stroke;
+
+        Region {
+            boundary: Boundary::HalfOpen,
+            x_extent: (self.x as f64, self.x as f64 + self.width as f64),
+            y_extent: (self.y as f64, self.y as f64 + self.height as f64),
+            row_intervals: move |y| {
+                let Some((lo, hi)) = outer.interval(y).filter(|_| stroke > 0) else {
+                    return Vec::new();
+                };
+                match inner.and_then(|inner| inner.interval(y)) {
+                    Some((inner_lo, inner_hi)) => vec![(lo, inner_lo), (inner_hi, hi)],
+                    None => vec![(lo, hi)],
+                }
+            },
+        }
+    }
+}
+
+impl Shape for RoundedRectangle {
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        self.region().bounds()
+    }
+
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)> {
+        self.region().row_spans(y).into_iter()
+    }
+}
+
+impl Shape for RoundedRectangleOutline {
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        self.region().bounds()
+    }
+
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)> {
+        self.region().row_spans(y).into_iter()
+    }
+}
@@ -0,0 +1,288 @@
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+use std::ops::RangeInclusive;
+
+use crate::{Pixel, shapes::Shape};
+use crate::shapes::line::center;
+use crate::shapes::raster::{
+    Boundary, Extent, Point, Polygon, Region, RegularCorners, capsule_interval, convex_polygon_interval,
+    points_extent,
+};
+
+/// Represents a filled star with `points` tips around a center pixel.
+///
+/// The tips lie on a circle of `radius` pixels around the center of `center`, and the
+/// notches between them on a circle of `radius * inner_ratio`. With a `rotation` of 0
+/// the first tip points straight up, as for [`RegularPolygon`].
+///
+/// Pixels are included if their center lies inside the star or on its edges. The star is
+/// split into triangles fanning out from its center, and spans are computed
+/// analytically per row. Stars with more corners than columns are instead tested pixel by
+/// pixel, against only the triangles around each one.
+///
+/// ## Edge Cases
+///
+/// - Fewer than 2 points or a radius of 0 produces an empty star
+/// - An `inner_ratio` of 0 or less collapses the star to lines, which are empty
+/// - An `inner_ratio` of `cos(π / points)` puts the notches on the lines between tips,
+///   giving a regular polygon
+///
+/// [`RegularPolygon`]: crate::shapes::RegularPolygon
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Star {
+    /// Center of the star
+    pub center: Pixel,
+    /// Distance from the center to each tip, in pixels
+    pub radius: u16,
+    /// Number of tips
+    pub points: u16,
+    /// Distance from the center to each notch, relative to `radius`
+    pub inner_ratio: f32,
+    /// Counterclockwise rotation in radians
+    pub rotation: f32,
+}
+
+/// Represents a star border with adjustable stroke width.
+///
+/// ## Stroke Behavior
+///
+/// The `stroke` parameter defines the thickness of the border in pixels, measured inward
+/// from the edges of the corresponding [`Star`], with the same rule as
+/// [`TriangleOutline`](crate::shapes::TriangleOutline).
+///
+/// ## Edge Cases
+///
+/// - A stroke of 0 produces an empty outline
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct StarOutline {
+    /// Center of the star
+    pub center: Pixel,
+    /// Distance from the center to each tip, in pixels
+    pub radius: u16,
+    /// Number of tips
+    pub points: u16,
+    /// Distance from the center to each notch, relative to `radius`
+    pub inner_ratio: f32,
+    /// Counterclockwise rotation in radians
+    pub rotation: f32,
+    /// Thickness of the outline in pixels
+    pub stroke: u16,
+}
+
+impl Star {
+    fn inner_radius(&self) -> f64 {
+        self.radius as f64 * self.inner_ratio.max(0.0) as f64
+    }
+
+    fn fan(&self) -> Fan {
+        Fan::new(*self, None)
+    }
+}
+
+impl StarOutline {
+    fn fan(&self) -> Fan {
+        let Self { center, radius, points, inner_ratio, rotation, stroke } = *self;
+        Fan::new(Star { center, radius, points, inner_ratio, rotation }, Some(stroke))
+    }
+}
+
+/// A star as triangles fanning out from its center, with corners computed on demand.
+struct Fan {
+    star: Star,
+    origin: Point,
+    /// Tips and notches alternate, so both are corners of a polygon with twice the count.
+    tips: RegularCorners,
+    notches: RegularCorners,
+    /// Number of corners, or 0 if the star is empty.
+    count: usize,
+    stroke: Option<u16>,
+}
+
+impl Fan {
+    fn new(star: Star, stroke: Option<u16>) -> Self {
+        let origin = center(star.center);
+        let count = if star.points < 2 || star.radius == 0 { 0 } else { star.points as usize * 2 };
+        let rotation = star.rotation as f64;
+        Self {
+            star,
+            origin,
+            tips: RegularCorners::new(origin, star.radius as f64, count, rotation),
+            notches: RegularCorners::new(origin, star.inner_radius(), count, rotation),
+            count,
+            stroke,
+        }
+    }
+
+    fn corner(&self, i: i64) -> Point {
+        if i.rem_euclid(2) == 0 { self.tips.corner(i) } else { self.notches.corner(i) }
+    }
+
+    /// Horizontal and vertical extents of the corners, taken from the polygon through the
+    /// tips and the one through the notches.
+    fn extent(&self) -> Extent {
+        if self.count == 0 {
+            return points_extent(&[]);
+        }
+        let Star { radius, points, rotation, .. } = self.star;
+        let (points, rotation) = (points as usize, rotation as f64);
+        let tips = RegularCorners::new(self.origin, radius as f64, points, rotation);
+        let offset = PI / points as f64;
+        let notches = RegularCorners::new(self.origin, self.star.inner_radius(), points, rotation + offset);
+
+        let ((a, b), (c, d)) = tips.extent();
+        let ((e, f), (g, h)) = notches.extent();
+        ((a.min(e), b.max(f)), (c.min(g), d.max(h)))
+    }
+
+    fn polygon(&self) -> Polygon {
+        let boundary: Vec<_> = (0..self.count as i64).map(|i| self.corner(i)).collect();
+        let pieces = (0..boundary.len())
+            .map(|i| vec![self.origin, boundary[i], boundary[(i + 1) % boundary.len()]])
+            .collect();
+
+        let polygon = Polygon { pieces, boundary, stroke: None };
+        match self.stroke {
+            Some(stroke) => polygon.outline(stroke),
+            None => polygon,
+        }
+    }
+
+    /// The star's region. Rows come from the whole polygon while the star has no more
+    /// corners than columns, and otherwise from testing each pixel against the few
+    /// triangles around it, so the cost per row grows with the smaller of the two.
+    fn region(&self) -> Region<impl Fn(f64) -> Vec<(f64, f64)> + '_> {
+        let (x_extent, y_extent) = self.extent();
+        let columns = Boundary::Closed.range(x_extent.0, x_extent.1);
+        let polygon = columns.filter(|&columns| !self.by_pixel(columns)).map(|_| self.polygon());
+
+        Region {
+            boundary: Boundary::Closed,
+            x_extent,
+            y_extent,
+            row_intervals: move |y| match (&polygon, columns) {
+                (Some(polygon), _) => polygon.row_intervals(y),
+                (None, Some((start, end))) => self.pixel_intervals(start..=end, y),
+                (None, None) => Vec::new(),
+            },
+        }
+    }
+
+    /// Whether rows are found pixel by pixel, because the star has more corners than the
+    /// `columns` it spans.
+    fn by_pixel(&self, (start, end): (u16, u16)) -> bool {
+        self.count > (end - start) as usize + 1
+    }
+
+    /// Whether the star covers `pixel`, testing only that pixel when rows are found pixel
+    /// by pixel.
+    fn has(&self, pixel: Pixel) -> bool {
+        let region = self.region();
+        let Some((min_x, min_y, max_x, max_y)) = region.bounds() else {
+            return false;
+        };
+        if pixel.x < min_x || pixel.x > max_x || pixel.y < min_y || pixel.y > max_y {
+            return false;
+        }
+
+        if self.by_pixel((min_x, max_x)) {
+            return self.contains(pixel.x, pixel.y as f64 + 0.5);
+        }
+        region.row_spans(pixel.y).iter().any(|&(start, end)| start <= pixel.x && pixel.x <= end)
+    }
+
+    /// The centers of the pixels in `columns` that lie in the star at height `y`, as
+    /// intervals.
+    fn pixel_intervals(&self, columns: RangeInclusive<u16>, y: f64) -> Vec<(f64, f64)> {
+        let mut intervals: Vec<(f64, f64)> = Vec::new();
+        for x in columns.filter(|&x| self.contains(x, y)) {
+            let center = x as f64 + 0.5;
+            match intervals.last_mut() {
+                Some(last) if last.1 + 1.0 == center => last.1 = center,
+                _ => intervals.push((center, center)),
+            }
+        }
+        intervals
+    }
+
+    /// Whether the center of pixel `x` at height `y` lies in the star, by the same rule as
+    /// [`Polygon`] but visiting only the triangles and edges near it.
+    fn contains(&self, x: u16, y: f64) -> bool {
+        let covers = |interval: Option<(f64, f64)>| {
+            let range = interval.and_then(|(lo, hi)| Boundary::Closed.range(lo, hi));
+            range.is_some_and(|(start, end)| start <= x && x <= end)
+        };
+
+        // The triangle between corners `i` and `i + 1` covers the angles between them.
+        let (dx, dy) = (x as f64 + 0.5 - self.origin.0, self.origin.1 - y);
+        let sector = self.tips.position(dy.atan2(dx)).floor() as i64;
+        let triangle = |i: i64| vec![self.origin, self.corner(i), self.corner(i + 1)];
+        if !(sector - 1..=sector + 1).any(|i| covers(convex_polygon_interval(&triangle(i), y))) {
+            return false;
+        }
+
+        let Some(stroke) = self.stroke else {
+            return true;
+        };
+        if stroke == 0 {
+            return false;
+        }
+        let radius = stroke as f64 - 0.5;
+
+        // Every edge lies as far from the center as the first one, and an edge `gap`
+        // radians away around it lies at least `distance * sin(gap)` from the pixel.
+        // Both bounds are stretched a little so rounding never cuts the search short.
+        let reach = radius + 1e-6;
+        let distance = dx.hypot(dy);
+        if distance + reach < segment_distance(self.origin, self.corner(0), self.corner(1)) {
+            return false;
+        }
+        let step = TAU / self.count as f64;
+        let near = |i: i64| covers(capsule_interval(self.corner(i), self.corner(i + 1), radius, y));
+        for offset in 0..=self.count as i64 / 2 {
+            let gap = ((offset - 1).max(0) as f64 * step).min(FRAC_PI_2);
+            if distance * gap.sin() > reach {
+                break;
+            }
+            if near(sector + offset) || near(sector - offset) {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// Distance from `point` to the segment from `a` to `b`.
+fn segment_distance(point: Point, a: Point, b: Point) -> f64 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let length = dx * dx + dy * dy;
+    let t = if length > 0.0 { ((point.0 - a.0) * dx + (point.1 - a.1) * dy) / length } else { 0.0 };
+    let t = t.clamp(0.0, 1.0);
+    (point.0 - a.0 - t * dx).hypot(point.1 - a.1 - t * dy)
+}
+
+impl Shape for Star {
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        self.fan().region().bounds()
+    }
+
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)> {
+        self.fan().region().row_spans(y).into_iter()
+    }
+
+    fn has(&self, pixel: Pixel) -> bool {
+        self.fan().has(pixel)
+    }
+}
+
+impl Shape for StarOutline {
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        self.fan().region().bounds()
+    }
+
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)> {
+        self.fan().region().row_spans(y).into_iter()
+    }
+
+    fn has(&self, pixel: Pixel) -> bool {
+        self.fan().has(pixel)
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{Pixel, shapes::Shape};
+use crate::shapes::line::center;
+use crate::shapes::raster::Polygon;
+
+/// Represents a filled triangle with corners at three pixels.
+///
+/// The triangle's corners are the centers of `a`, `b` and `c`, in either winding order.
+/// Pixels are included if their center lies inside the triangle or on its edges, so the
+/// corner pixels themselves are always part of it. Spans are computed analytically per row.
+///
+/// ## Edge Cases
+///
+/// - Collinear corners enclose no area and produce an empty triangle
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Triangle {
+    /// First corner
+    pub a: Pixel,
+    /// Second corner
+    pub b: Pixel,
+    /// Third corner
+    pub c: Pixel,
+}
+
+/// Represents a triangle border with adjustable stroke width.
+///
+/// ## Stroke Behavior
+///
+/// The `stroke` parameter defines the thickness of the border in pixels, measured inward
+/// from the edges of the corresponding [`Triangle`]. A pixel is included if it's inside
+/// the triangle and its center is within `stroke - 0.5` of an edge, so axis-aligned
+/// edges are exactly `stroke` pixels thick.
+///
+/// ## Edge Cases
+///
+/// - If the stroke reaches the middle of the triangle, the entire triangle is included
+/// - A stroke of 0 produces an empty outline
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct TriangleOutline {
+    /// First corner
+    pub a: Pixel,
+    /// Second corner
+    pub b: Pixel,
+    /// Third corner
+    pub c: Pixel,
+    /// Thickness of the outline in pixels
+    pub stroke: u16,
+}
+
+impl Triangle {
+    fn polygon(&self) -> Polygon {
+        Polygon::convex(vec![center(self.a), center(self.b), center(self.c)])
+    }
+}
+
+impl TriangleOutline {
+    fn polygon(&self) -> Polygon {
+        Triangle { a: self.a, b: self.b, c: self.c }.polygon().outline(self.stroke)
+    }
+}
+
+impl Shape for Triangle {
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        self.polygon().region().bounds()
+    }
+
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)> {
+        self.polygon().region().row_spans(y).into_iter()
+    }
+}
+
+impl Shape for TriangleOutline {
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        self.polygon().region().bounds()
+    }
+
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)> {
+        self.polygon().region().row_spans(y).into_iter()
+    }
+}
@@ -0,0 +1,438 @@
+use image::{DynamicImage, ImageBuffer, Rgba, RgbaImage};
+use pixelset::{Color, Pixel, PixelSet};
+use pixelset::color::{ColorMetric, ColorParseError, CompositeMode, Hsl, Hsv, Lab, LinearRgb, Oklab, Palette};
+
+fn assert_close(actual: f32, expected: f32, tolerance: f32) {
+    assert!(
+        (actual - expected).abs() <= tolerance,
+        "expected {} to be within {} of {}", actual, tolerance, expected
+    );
+}
+
+#[test]
+fn test_hsv_primaries() {
+    let red = Color::new(255, 0, 0, 255).to_hsv();
+    assert_close(red.h, 0.0, 1e-4);
+    assert_close(red.s, 1.0, 1e-4);
+    assert_close(red.v, 1.0, 1e-4);
+
+    let teal = Color::new(0, 128, 128, 255).to_hsv();
+    assert_close(teal.h, 180.0, 1e-3);
+    assert_close(teal.v, 128.0 / 255.0, 1e-4);
+
+    let gray = Color::new(100, 100, 100, 255).to_hsv();
+    assert_close(gray.s, 0.0, 1e-6);
+}
+
+#[test]
+fn test_hsl_primaries() {
+    let blue = Color::new(0, 0, 255, 255).to_hsl();
+    assert_close(blue.h, 240.0, 1e-3);
+    assert_close(blue.s, 1.0, 1e-4);
+    assert_close(blue.l, 0.5, 1e-4);
+
+    let color: Color = Hsl { h: 120.0, s: 1.0, l: 0.25, alpha: 1.0 }.into();
+    assert_eq!(color, Color::new(0, 128, 0, 255));
+}
+
+#[test]
+fn test_hue_wraps_around() {
+    let color: Color = Hsv { h: 360.0 + 60.0, s: 1.0, v: 1.0, alpha: 1.0 }.into();
+    assert_eq!(color, Color::new(255, 255, 0, 255));
+
+    let color: Color = Hsv { h: -120.0, s: 1.0, v: 1.0, alpha: 1.0 }.into();
+    assert_eq!(color, Color::new(0, 0, 255, 255));
+}
+
+#[test]
+fn test_lab_reference_values() {
+    let white = Color::new(255, 255, 255, 255).to_lab();
+    assert_close(white.l, 100.0, 0.01);
+    assert_close(white.a, 0.0, 0.01);
+    assert_close(white.b, 0.0, 0.01);
+
+    let red = Color::new(255, 0, 0, 255).to_lab();
+    assert_close(red.l, 53.24, 0.05);
+    assert_close(red.a, 80.09, 0.05);
+    assert_close(red.b, 67.20, 0.05);
+}
+
+#[test]
+fn test_oklab_reference_values() {
+    let white = Color::new(255, 255, 255, 255).to_oklab();
+    assert_close(white.l, 1.0, 1e-3);
+    assert_close(white.a, 0.0, 1e-3);
+
+    let red = Color::new(255, 0, 0, 255).to_oklab();
+    assert_close(red.l, 0.628, 1e-3);
+    assert_close(red.a, 0.2249, 1e-3);
+    assert_close(red.b, 0.1258, 1e-3);
+}
+
+#[test]
+fn test_linear_rgb_transfer() {
+    let mid = Color::new(128, 128, 128, 255).to_linear();
+    assert_close(mid.r, 0.2158, 1e-3);
+
+    let back: Color = LinearRgb { r: 0.2158, g: 0.0, b: 1.0, alpha: 0.5 }.into();
+    assert_eq!(back, Color::new(128, 0, 255, 128));
+}
+
+#[test]
+fn test_color_space_roundtrips() {
+    for r in (0..=255).step_by(51) {
+        for g in (0..=255).step_by(51) {
+            for b in (0..=255).step_by(51) {
+                let color = Color::new(r as u8, g as u8, b as u8, 200);
+
+                assert_eq!(Color::from(color.to_hsv()), color);
+                assert_eq!(Color::from(color.to_hsl()), color);
+                assert_eq!(Color::from(color.to_linear()), color);
+                assert_eq!(Color::from(color.to_lab()), color);
+                assert_eq!(Color::from(color.to_oklab()), color);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_out_of_gamut_values_clamp() {
+    let color: Color = Lab { l: 150.0, a: 0.0, b: 0.0, alpha: 2.0 }.into();
+    assert_eq!(color, Color::new(255, 255, 255, 255));
+
+    let color: Color = Oklab { l: -1.0, a: 0.0, b: 0.0, alpha: -1.0 }.into();
+    assert_eq!(color, Color::new(0, 0, 0, 0));
+}
+
+#[test]
+fn test_distance_identical_colors_is_zero() {
+    let color = Color::new(12, 200, 99, 255);
+    for metric in [
+        ColorMetric::Euclidean, ColorMetric::WeightedRgb, ColorMetric::Cie76,
+        ColorMetric::Ciede2000, ColorMetric::Oklab,
+    ] {
+        assert_close(color.distance(color, metric), 0.0, 1e-4);
+    }
+}
+
+#[test]
+fn test_distance_rgb_metrics() {
+    let black = Color::new(0, 0, 0, 255);
+    let white = Color::new(255, 255, 255, 0);
+
+    assert_close(black.distance(white, ColorMetric::Euclidean), 441.673, 1e-2);
+    assert_close(black.distance(Color::new(3, 4, 0, 255), ColorMetric::Euclidean), 5.0, 1e-4);
+    assert_close(black.distance(white, ColorMetric::WeightedRgb), 764.833, 1e-2);
+}
+
+#[test]
+fn test_distance_lab_metrics() {
+    let black = Color::new(0, 0, 0, 255);
+    let white = Color::new(255, 255, 255, 255);
+
+    assert_close(black.distance(white, ColorMetric::Cie76), 100.0, 0.01);
+    assert_close(black.distance(white, ColorMetric::Ciede2000), 100.0, 0.01);
+    assert_close(black.distance(white, ColorMetric::Oklab), 1.0, 1e-3);
+}
+
+#[test]
+fn test_ciede2000_reference_pairs() {
+    // Test data from Sharma, Wu and Dalal (2005).
+    let pairs = [
+        ((50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485), 2.0425),
+        ((50.0, 0.0, 0.0), (50.0, -1.0, 2.0), 2.3669),
+        ((50.0, 2.5, 0.0), (73.0, 25.0, -18.0), 27.1492),
+        ((60.2574, -34.0099, 36.2677), (60.4626, -34.1751, 39.4387), 1.2644),
+    ];
+
+    for ((l1, a1, b1), (l2, a2, b2), expected) in pairs {
+        let x = Lab { l: l1, a: a1, b: b1, alpha: 1.0 };
+        let y = Lab { l: l2, a: a2, b: b2, alpha: 1.0 };
+        assert_close(x.delta_e2000(y), expected, 1e-3);
+        assert_close(y.delta_e2000(x), expected, 1e-3);
+    }
+}
+
+#[test]
+fn test_select_similar() {
+    let mut buf: RgbaImage = ImageBuffer::new(4, 1);
+    buf.put_pixel(0, 0, Rgba([200, 30, 30, 255]));
+    buf.put_pixel(1, 0, Rgba([205, 28, 33, 255]));
+    buf.put_pixel(2, 0, Rgba([30, 30, 200, 255]));
+    buf.put_pixel(3, 0, Rgba([200, 30, 30, 255]));
+    let image = DynamicImage::ImageRgba8(buf);
+
+    let red = Color::new(200, 30, 30, 255);
+    let full = PixelSet::from_image(&image);
+
+    let exact = full.select_similar(&image, red, ColorMetric::Ciede2000, 0.0);
+    assert_eq!(exact, full.select(&image, red));
+
+    let near = full.select_similar(&image, red, ColorMetric::Ciede2000, 3.0);
+    near.validate_invariants().expect("Similar selection has invalid invariants");
+    assert_eq!(near.len(), 3);
+    assert!(!near.has(Pixel::new(2, 0)));
+
+    let any = full.select_similar_any(&image, &[red, Color::new(30, 30, 200, 255)], ColorMetric::Oklab, 0.02);
+    assert_eq!(any.len(), 4);
+}
+
+#[test]
+fn test_composite_over() {
+    let white = Color::new(255, 255, 255, 255);
+    let half_red = Color::new(255, 0, 0, 128);
+
+    assert_eq!(white.composite(half_red, CompositeMode::Over), Color::new(255, 127, 127, 255));
+
+    let transparent = Color::new(0, 0, 0, 0);
+    assert_eq!(transparent.composite(half_red, CompositeMode::Over), half_red);
+    assert_eq!(white.composite(transparent, CompositeMode::Over), white);
+}
+
+#[test]
+fn test_composite_porter_duff_operators() {
+    let red = Color::new(255, 0, 0, 255);
+    let half_blue = Color::new(0, 0, 255, 128);
+    let transparent = Color::new(0, 0, 0, 0);
+
+    assert_eq!(half_blue.composite(red, CompositeMode::In), Color::new(255, 0, 0, 128));
+    assert_eq!(half_blue.composite(red, CompositeMode::Out), Color::new(255, 0, 0, 127));
+    assert_eq!(transparent.composite(red, CompositeMode::In), transparent);
+    assert_eq!(transparent.composite(red, CompositeMode::Atop), transparent);
+
+    let opaque_blue = Color::new(0, 0, 255, 255);
+    assert_eq!(opaque_blue.composite(red, CompositeMode::Atop), red);
+    assert_eq!(opaque_blue.composite(red, CompositeMode::Xor), transparent);
+}
+
+#[test]
+fn test_composite_blend_modes() {
+    let backdrop = Color::new(200, 100, 50, 255);
+
+    assert_eq!(
+        backdrop.composite(Color::new(255, 128, 0, 255), CompositeMode::Multiply),
+        Color::new(200, 50, 0, 255)
+    );
+    assert_eq!(backdrop.composite(Color::new(0, 0, 0, 255), CompositeMode::Screen), backdrop);
+    assert_eq!(
+        backdrop.composite(Color::new(100, 100, 100, 255), CompositeMode::Darken),
+        Color::new(100, 100, 50, 255)
+    );
+    assert_eq!(
+        backdrop.composite(Color::new(100, 100, 100, 255), CompositeMode::Lighten),
+        Color::new(200, 100, 100, 255)
+    );
+    assert_eq!(
+        backdrop.composite(Color::new(100, 100, 100, 255), CompositeMode::Difference),
+        Color::new(100, 0, 50, 255)
+    );
+    assert_eq!(
+        Color::new(0, 255, 128, 255).composite(Color::new(100, 100, 100, 255), CompositeMode::Overlay),
+        Color::new(0, 255, 101, 255)
+    );
+}
+
+#[test]
+fn test_composite_blend_mode_over_transparent_backdrop() {
+    let transparent = Color::new(0, 0, 0, 0);
+    let source = Color::new(10, 20, 30, 255);
+
+    assert_eq!(transparent.composite(source, CompositeMode::Multiply), source);
+}
+
+#[test]
+fn test_pixelset_composite() {
+    let buf: RgbaImage = ImageBuffer::from_pixel(3, 1, Rgba([255, 255, 255, 255]));
+    let mut image = DynamicImage::ImageRgba8(buf);
+
+    let set = PixelSet::new(vec![Pixel::new(0, 0), Pixel::new(1, 0)]);
+    set.composite(&mut image, Color::new(0, 0, 0, 255), CompositeMode::Over, 128);
+
+    assert_eq!(Pixel::new(0, 0).color(&image), Color::new(127, 127, 127, 255));
+    assert_eq!(Pixel::new(1, 0).color(&image), Color::new(127, 127, 127, 255));
+    assert_eq!(Pixel::new(2, 0).color(&image), Color::new(255, 255, 255, 255));
+
+    set.composite(&mut image, Color::new(0, 0, 0, 255), CompositeMode::Over, 0);
+    assert_eq!(Pixel::new(0, 0).color(&image), Color::new(127, 127, 127, 255));
+
+    set.composite(&mut image, Color::new(0, 0, 0, 255), CompositeMode::Xor, 0);
+    assert_eq!(Pixel::new(0, 0).color(&image), Color::new(127, 127, 127, 255));
+}
+
+#[test]
+fn test_pixelset_composite_fades_operators_by_opacity() {
+    let buf: RgbaImage = ImageBuffer::from_pixel(2, 1, Rgba([255, 255, 255, 255]));
+    let set = PixelSet::new(vec![Pixel::new(0, 0)]);
+    let black = Color::new(0, 0, 0, 255);
+
+    // At zero opacity every operator leaves the image unchanged.
+    for mode in [CompositeMode::In, CompositeMode::Out] {
+        let mut image = DynamicImage::ImageRgba8(buf.clone());
+        set.composite(&mut image, black, mode, 0);
+        assert_eq!(image, DynamicImage::ImageRgba8(buf.clone()), "{:?}", mode);
+    }
+
+    // At half opacity the pixel lies halfway between the backdrop and the full result.
+    let mut image = DynamicImage::ImageRgba8(buf.clone());
+    set.composite(&mut image, black, CompositeMode::In, 128);
+    assert_eq!(Pixel::new(0, 0).color(&image), Color::new(127, 127, 127, 255));
+    assert_eq!(Pixel::new(1, 0).color(&image), Color::new(255, 255, 255, 255));
+
+    let mut image = DynamicImage::ImageRgba8(buf.clone());
+    set.composite(&mut image, black, CompositeMode::Out, 128);
+    assert_eq!(Pixel::new(0, 0).color(&image), Color::new(255, 255, 255, 127));
+
+    // At full opacity the operator applies as-is.
+    let mut image = DynamicImage::ImageRgba8(buf);
+    set.composite(&mut image, black, CompositeMode::Out, 255);
+    assert_eq!(Pixel::new(0, 0).color(&image), Color::new(0, 0, 0, 0));
+}
+
+#[test]
+fn test_hex_shorthand() {
+    assert_eq!(Color::hex("#F0A").unwrap(), Color::new(255, 0, 170, 255));
+    assert_eq!(Color::hex("f0a8").unwrap(), Color::new(255, 0, 170, 136));
+    assert_eq!(Color::hex("#FF00AA").unwrap(), Color::new(255, 0, 170, 255));
+
+    assert!(matches!(Color::hex("#12345"), Err(ColorParseError::InvalidLength(5))));
+    assert!(matches!(Color::hex("#12G"), Err(ColorParseError::InvalidHex("B", _))));
+    assert!(Color::hex("#ééé").is_err());
+}
+
+#[test]
+fn test_parse_functional_notation() {
+    let parse = |s: &str| s.parse::<Color>().unwrap();
+
+    assert_eq!(parse("rgb(255, 128, 0)"), Color::new(255, 128, 0, 255));
+    assert_eq!(parse("RGBA(255, 128, 0, 0.5)"), Color::new(255, 128, 0, 128));
+    assert_eq!(parse("rgb(255 128 0 / 25%)"), Color::new(255, 128, 0, 64));
+    assert_eq!(parse("rgb(100%, 0%, 50%)"), Color::new(255, 0, 128, 255));
+    assert_eq!(parse("rgb(300, -20, 0)"), Color::new(255, 0, 0, 255));
+
+    assert_eq!(parse("hsl(120, 100%, 50%)"), Color::new(0, 255, 0, 255));
+    assert_eq!(parse("hsla(240deg 100% 50% / 0.5)"), Color::new(0, 0, 255, 128));
+    assert_eq!(parse("  hsl(0, 0%, 100%)  "), Color::new(255, 255, 255, 255));
+}
+
+#[test]
+fn test_parse_named_colors() {
+    let parse = |s: &str| s.parse::<Color>().unwrap();
+
+    assert_eq!(parse("aliceblue"), Color::new(240, 248, 255, 255));
+    assert_eq!(parse("RebeccaPurple"), Color::new(102, 51, 153, 255));
+    assert_eq!(parse("yellowgreen"), Color::new(154, 205, 50, 255));
+    assert_eq!(parse("grey"), parse("gray"));
+    assert_eq!(parse("transparent"), Color::new(0, 0, 0, 0));
+    assert_eq!(parse("bada55"), Color::new(186, 218, 85, 255));
+}
+
+#[test]
+fn test_parse_errors() {
+    let parse = |s: &str| s.parse::<Color>();
+
+    assert!(matches!(parse("   "), Err(ColorParseError::Empty)));
+    assert!(matches!(parse("notacolor"), Err(ColorParseError::UnknownName(_))));
+    assert!(matches!(parse("cmyk(0, 0, 0, 0)"), Err(ColorParseError::UnknownFunction(_))));
+    assert!(matches!(parse("rgb(0, 0, 0"), Err(ColorParseError::MalformedFunction(_))));
+    assert!(matches!(
+        parse("rgb(0, 0)"),
+        Err(ColorParseError::InvalidComponentCount { function: "rgb", count: 2 })
+    ));
+    assert!(matches!(parse("hsl(red, 0%, 0%)"), Err(ColorParseError::InvalidComponent(_))));
+}
+
+#[test]
+fn test_display_round_trip() {
+    let opaque = Color::new(18, 52, 86, 255);
+    let translucent = Color::new(18, 52, 86, 120);
+
+    assert_eq!(opaque.to_string(), "#123456");
+    assert_eq!(translucent.to_string(), "#12345678");
+    assert_eq!(opaque.to_string().parse::<Color>().unwrap(), opaque);
+    assert_eq!(translucent.to_string().parse::<Color>().unwrap(), translucent);
+}
+
+#[test]
+fn test_deserialize_css_notation() {
+    let colors: Vec<Color> = serde_json::from_str(r##"["red", "#0f08", "hsl(240, 100%, 50%)"]"##).unwrap();
+    assert_eq!(
+        colors,
+        vec![
+            Color::new(255, 0, 0, 255),
+            Color::new(0, 255, 0, 136),
+            Color::new(0, 0, 255, 255),
+        ]
+    );
+    assert_eq!(serde_json::to_string(&colors[1]).unwrap(), r##""#00FF0088""##);
+}
+
+#[test]
+fn test_palette_nearest() {
+    let red = Color::new(255, 0, 0, 255);
+    let green = Color::new(0, 255, 0, 255);
+    let blue = Color::new(0, 0, 255, 255);
+    let palette = Palette::new(vec![red, green, blue]).with_metric(ColorMetric::Oklab);
+
+    assert_eq!(palette.metric(), ColorMetric::Oklab);
+    assert_eq!(palette.nearest_index(Color::new(200, 40, 30, 255)), Some(0));
+    assert_eq!(palette.nearest(Color::new(10, 30, 220, 255)), Some(blue));
+    assert_eq!(Palette::new(Vec::new()).nearest(red), None);
+}
+
+#[test]
+fn test_median_cut_recovers_clusters() {
+    let mut colors = Vec::new();
+    for i in 0..10 {
+        colors.push(Color::new(250 - i, i, 0, 255));
+        colors.push(Color::new(i, 5, 245 + i, 255));
+    }
+
+    let palette = Palette::median_cut(colors.iter().copied(), 2);
+    assert_eq!(palette.len(), 2);
+    for color in palette.colors() {
+        let reddish = color.r() > 200 && color.b() < 20;
+        let bluish = color.b() > 200 && color.r() < 20;
+        assert!(reddish || bluish, "unexpected center {}", color);
+    }
+
+    assert_eq!(Palette::median_cut(vec![Color::new(1, 2, 3, 255); 5], 4).len(), 1);
+    assert!(Palette::median_cut(Vec::new(), 4).is_empty());
+    assert!(Palette::median_cut(colors, 0).is_empty());
+}
+
+#[test]
+fn test_kmeans_refines_centers() {
+    let mut colors = vec![Color::new(0, 0, 0, 255); 30];
+    colors.extend(vec![Color::new(20, 20, 20, 255); 30]);
+    colors.extend(vec![Color::new(255, 255, 255, 255); 40]);
+
+    let palette = Palette::kmeans(colors, 2, 10);
+    let mut centers = palette.colors().to_vec();
+    centers.sort_by_key(|c| c.r());
+    assert_eq!(centers, vec![Color::new(10, 10, 10, 255), Color::new(255, 255, 255, 255)]);
+
+    assert!(Palette::kmeans(Vec::new(), 3, 10).is_empty());
+}
+
+#[test]
+fn test_pixelset_quantize() {
+    let mut buf: RgbaImage = ImageBuffer::new(4, 1);
+    buf.put_pixel(0, 0, Rgba([250, 10, 10, 255]));
+    buf.put_pixel(1, 0, Rgba([10, 10, 240, 255]));
+    buf.put_pixel(2, 0, Rgba([240, 20, 0, 255]));
+    buf.put_pixel(3, 0, Rgba([0, 0, 250, 255]));
+    let mut image = DynamicImage::ImageRgba8(buf);
+
+    let red = Color::new(255, 0, 0, 255);
+    let blue = Color::new(0, 0, 255, 255);
+    let set = PixelSet::new(vec![Pixel::new(0, 0), Pixel::new(1, 0), Pixel::new(2, 0)]);
+    set.quantize(&mut image, &Palette::from(vec![red, blue]));
+
+    assert_eq!(Pixel::new(0, 0).color(&image), red);
+    assert_eq!(Pixel::new(1, 0).color(&image), blue);
+    assert_eq!(Pixel::new(2, 0).color(&image), red);
+    assert_eq!(Pixel::new(3, 0).color(&image), Color::new(0, 0, 250, 255));
+
+    let extracted = Palette::median_cut(set.as_colors(&image), 8);
+    assert_eq!(extracted.len(), 2);
+}
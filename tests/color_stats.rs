@@ -0,0 +1,119 @@
+use image::{DynamicImage, ImageBuffer, Rgba, RgbaImage};
+use pixelset::{Color, Pixel, PixelSet};
+
+fn gradient_image() -> RgbaImage {
+    ImageBuffer::from_fn(16, 8, |x, y| {
+        Rgba([(x * 16) as u8, (y * 32) as u8, ((x + y) * 8) as u8, 255 - (x as u8)])
+    })
+}
+
+fn all_pixels(width: u16, height: u16) -> PixelSet {
+    let mut pixels = Vec::new();
+    for y in 0..height {
+        for x in 0..width {
+            pixels.push(Pixel::new(x, y));
+        }
+    }
+    PixelSet::new(pixels)
+}
+
+#[test]
+fn test_color_stats_basic() {
+    let mut buf: RgbaImage = ImageBuffer::new(4, 1);
+    buf.put_pixel(0, 0, Rgba([0, 10, 100, 255]));
+    buf.put_pixel(1, 0, Rgba([10, 10, 100, 255]));
+    buf.put_pixel(2, 0, Rgba([20, 10, 200, 255]));
+    buf.put_pixel(3, 0, Rgba([31, 10, 200, 0]));
+    let image = DynamicImage::ImageRgba8(buf);
+
+    let stats = all_pixels(4, 1).color_stats(&image).unwrap();
+    assert_eq!(stats.count, 4);
+
+    assert_eq!(stats.r.mean, 15.25);
+    assert_eq!(stats.r.min, 0);
+    assert_eq!(stats.r.max, 31);
+    assert_eq!(stats.r.median, 10);
+    assert!((stats.r.variance - 132.6875).abs() < 1e-9);
+    assert_eq!(stats.r.histogram[31], 1);
+
+    assert_eq!(stats.g.variance, 0.0);
+    assert_eq!(stats.b.mean, 150.0);
+    assert_eq!(stats.mean(), Color::new(15, 10, 150, 191));
+    assert_eq!(stats.min(), Color::new(0, 10, 100, 0));
+    assert_eq!(stats.max(), Color::new(31, 10, 200, 255));
+    assert_eq!(stats.median(), Color::new(10, 10, 100, 255));
+}
+
+#[test]
+fn test_color_stats_empty() {
+    let image = DynamicImage::new_rgba8(4, 4);
+    assert!(PixelSet::empty().color_stats(&image).is_none());
+
+    let outside = PixelSet::new(vec![Pixel::new(10, 10)]);
+    assert!(outside.color_stats(&image).is_none());
+}
+
+#[test]
+fn test_color_stats_clips_to_image() {
+    let image = DynamicImage::ImageRgba8(ImageBuffer::from_pixel(4, 4, Rgba([9, 9, 9, 255])));
+    let set = all_pixels(8, 8);
+    assert_eq!(set.color_stats(&image).unwrap().count, 16);
+}
+
+#[test]
+fn test_color_stats_matches_get_pixel_for_all_formats() {
+    let base = DynamicImage::ImageRgba8(gradient_image());
+    let formats = [
+        DynamicImage::ImageLuma8(base.to_luma8()),
+        DynamicImage::ImageLumaA8(base.to_luma_alpha8()),
+        DynamicImage::ImageRgb8(base.to_rgb8()),
+        base.clone(),
+        DynamicImage::ImageLuma16(base.to_luma16()),
+        DynamicImage::ImageLumaA16(base.to_luma_alpha16()),
+        DynamicImage::ImageRgb16(base.to_rgb16()),
+        DynamicImage::ImageRgba16(base.to_rgba16()),
+        DynamicImage::ImageRgb32F(base.to_rgb32f()),
+        DynamicImage::ImageRgba32F(base.to_rgba32f()),
+    ];
+
+    let set = PixelSet::new(vec![
+        Pixel::new(1, 0), Pixel::new(2, 0), Pixel::new(3, 0),
+        Pixel::new(15, 3), Pixel::new(0, 7), Pixel::new(7, 7),
+    ]);
+
+    for image in &formats {
+        let stats = set.color_stats(image).unwrap();
+        let mut expected = [[0u64; 256]; 4];
+        for color in set.as_colors(image) {
+            expected[0][color.r() as usize] += 1;
+            expected[1][color.g() as usize] += 1;
+            expected[2][color.b() as usize] += 1;
+            expected[3][color.a() as usize] += 1;
+        }
+
+        for (channel, histogram) in stats.channels().iter().zip(expected) {
+            assert_eq!(channel.histogram, histogram, "mismatch for {:?}", image.color());
+        }
+    }
+}
+
+#[test]
+fn test_dominant_colors() {
+    let red = Color::new(200, 0, 0, 255);
+    let blue = Color::new(0, 0, 200, 255);
+    let buf: RgbaImage = ImageBuffer::from_fn(10, 1, |x, _| {
+        match x {
+            0..=6 => Rgba([200 + (x % 2) as u8, 0, 0, 255]),
+            _ => Rgba([0, 0, 200, 255]),
+        }
+    });
+    let image = DynamicImage::ImageRgba8(buf);
+
+    let dominant = all_pixels(10, 1).dominant_colors(&image, 2);
+    assert_eq!(dominant.len(), 2);
+    assert_eq!(dominant[0].1, 7);
+    assert!(dominant[0].0.distance(red, Default::default()) <= 1.0);
+    assert_eq!(dominant[1], (blue, 3));
+
+    assert!(PixelSet::empty().dominant_colors(&image, 3).is_empty());
+}
@@ -1,4 +1,5 @@
-use pixelset::{Pixel, PixelSet, Color, CompressedPixelSet};
+use pixelset::{Pixel, PixelSet, PixelSetArchive, Color, CompressedPixelSet, CompressionDictionary};
+use pixelset::compression::ArchiveReader;
 
 #[test]
 fn test_pixel_set_compress_decompress() {
@@ -226,3 +227,384 @@ fn test_compressed_pixelset_json_serialization_is_base64() {
     let deserialized: CompressedPixelSet = serde_json::from_str(&json).unwrap();
     assert_eq!(compressed.bytes(), deserialized.bytes());
 }
+
+#[test]
+fn test_streaming_compression_roundtrip() {
+    let mut pixels = vec![];
+    for y in 0..40 {
+        for x in (y % 3)..60 {
+            pixels.push(Pixel::new(x, y));
+        }
+    }
+    let original = PixelSet::new(pixels);
+
+    let mut buf = Vec::new();
+    original.write_compressed(&mut buf).unwrap();
+
+    let recovered = PixelSet::read_compressed(buf.as_slice()).unwrap();
+    recovered.validate_invariants().expect("Streamed set has invalid invariants");
+    assert_eq!(original, recovered);
+}
+
+#[test]
+fn test_streaming_compression_interoperates_with_compress() {
+    let original = PixelSet::new(vec![
+        Pixel::new(0, 0), Pixel::new(1, 0), Pixel::new(7, 3),
+    ]);
+
+    let mut streamed = Vec::new();
+    original.write_compressed(&mut streamed).unwrap();
+    let from_stream = CompressedPixelSet::new(streamed).decompress().unwrap();
+    assert_eq!(original, from_stream);
+
+    let buffered = original.compress().unwrap();
+    let from_buffer = PixelSet::read_compressed(buffered.bytes()).unwrap();
+    assert_eq!(original, from_buffer);
+}
+
+#[test]
+fn test_streaming_compression_empty() {
+    let mut buf = Vec::new();
+    PixelSet::empty().write_compressed(&mut buf).unwrap();
+
+    let recovered = PixelSet::read_compressed(buf.as_slice()).unwrap();
+    assert!(recovered.is_empty());
+}
+
+#[test]
+fn test_streaming_decompression_rejects_truncated_data() {
+    // Header claims two runs but only one follows.
+    let payload = [2u8, 0, 0, 0, 1, 0, 2, 0, 3, 0];
+    let compressed = zstd::encode_all(&payload[..], 3).unwrap();
+
+    let result = PixelSet::read_compressed(compressed.as_slice());
+    assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+}
+
+fn banded_fixture() -> PixelSet {
+    let mut pixels = vec![];
+    for y in 10..90 {
+        for x in (y / 2)..(y / 2 + 30) {
+            pixels.push(Pixel::new(x, y));
+        }
+    }
+    PixelSet::new(pixels)
+}
+
+#[test]
+fn test_indexed_compression_roundtrip() {
+    let original = banded_fixture();
+
+    let compressed = original.compress_indexed(16).unwrap();
+    let recovered = compressed.decompress().unwrap();
+
+    recovered.validate_invariants().expect("Indexed set has invalid invariants");
+    assert_eq!(original, recovered);
+}
+
+#[test]
+fn test_indexed_compression_queries() {
+    let original = banded_fixture();
+    let compressed = original.compress_indexed(8).unwrap();
+
+    assert_eq!(compressed.pixel_count().unwrap(), original.len());
+    assert_eq!(compressed.bounds().unwrap(), original.bounds());
+
+    let probes = [
+        Pixel::new(5, 10), Pixel::new(34, 10), Pixel::new(4, 10),
+        Pixel::new(50, 89), Pixel::new(0, 0), Pixel::new(60, 200),
+    ];
+    for pixel in probes {
+        assert_eq!(compressed.has(pixel).unwrap(), original.has(pixel), "mismatch at {:?}", pixel);
+    }
+}
+
+#[test]
+fn test_indexed_compression_row_range() {
+    let original = banded_fixture();
+    let compressed = original.compress_indexed(8).unwrap();
+
+    let rows = compressed.rows(20..35).unwrap();
+    rows.validate_invariants().expect("Row range has invalid invariants");
+
+    let expected = original.filter(|p| (20..35).contains(&p.y));
+    assert_eq!(rows, expected);
+    assert_eq!(compressed.rows(..).unwrap(), original);
+    assert!(compressed.rows(200..=300).unwrap().is_empty());
+}
+
+#[test]
+fn test_plain_compression_supports_queries() {
+    let original = banded_fixture();
+    let compressed = original.compress().unwrap();
+
+    assert_eq!(compressed.pixel_count().unwrap(), original.len());
+    assert_eq!(compressed.bounds().unwrap(), original.bounds());
+    assert!(compressed.has(Pixel::new(5, 10)).unwrap());
+    assert_eq!(compressed.rows(40..=40).unwrap().len(), 30);
+}
+
+#[test]
+fn test_indexed_compression_empty() {
+    let compressed = PixelSet::empty().compress_indexed(4).unwrap();
+
+    assert_eq!(compressed.pixel_count().unwrap(), 0);
+    assert_eq!(compressed.bounds().unwrap(), None);
+    assert!(!compressed.has(Pixel::new(0, 0)).unwrap());
+    assert!(compressed.decompress().unwrap().is_empty());
+}
+
+#[test]
+fn test_indexed_compression_rejects_zero_band_height() {
+    let result = banded_fixture().compress_indexed(0);
+    assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::InvalidInput);
+}
+
+/// A run as `(y, x_start, length)`.
+type RawRun = (u16, u16, u16);
+
+/// Builds a raw row-indexed payload from `(first_y, runs)` bands. The header claims
+/// one pixel at the origin.
+fn raw_indexed(rows_per_band: u16, bands: &[(u16, &[RawRun])]) -> Vec<u8> {
+    let mut index = vec![];
+    let mut data = vec![];
+    for (first_y, runs) in bands {
+        let mut raw = (runs.len() as u32).to_le_bytes().to_vec();
+        for &(y, x_start, length) in *runs {
+            for value in [y, x_start, length] {
+                raw.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        let compressed = zstd::encode_all(raw.as_slice(), 0).unwrap();
+
+        index.extend_from_slice(&first_y.to_le_bytes());
+        index.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        index.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+        data.extend_from_slice(&compressed);
+    }
+
+    let mut bytes = b"PXRI".to_vec();
+    bytes.extend_from_slice(&rows_per_band.to_le_bytes());
+    bytes.extend_from_slice(&(bands.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(&1u64.to_le_bytes());
+    bytes.extend_from_slice(&[0; 8]);
+    bytes.extend_from_slice(&index);
+    bytes.extend_from_slice(&data);
+    bytes
+}
+
+#[test]
+fn test_indexed_compression_rejects_malformed_bands() {
+    use std::io::ErrorKind::InvalidData;
+
+    let valid = CompressedPixelSet::new(raw_indexed(4, &[(0, &[(1, 2, 3)]), (4, &[(5, 0, 1)])]));
+    assert_eq!(valid.decompress().unwrap(), PixelSet::new(vec![
+        Pixel::new(2, 1), Pixel::new(3, 1), Pixel::new(4, 1), Pixel::new(0, 5),
+    ]));
+
+    let unsorted = CompressedPixelSet::new(raw_indexed(4, &[(4, &[(5, 0, 1)]), (0, &[(1, 2, 3)])]));
+    let overlapping = CompressedPixelSet::new(raw_indexed(4, &[(0, &[(1, 2, 3)]), (2, &[(3, 0, 1)])]));
+    for compressed in [unsorted, overlapping] {
+        assert_eq!(compressed.decompress().unwrap_err().kind(), InvalidData);
+        assert_eq!(compressed.has(Pixel::new(2, 1)).unwrap_err().kind(), InvalidData);
+        assert_eq!(compressed.bounds().unwrap_err().kind(), InvalidData);
+    }
+
+    let bad_runs: [&[RawRun]; 5] = [
+        &[(1, 4, 2), (0, 0, 1)],
+        &[(1, 0, 4), (1, 2, 4)],
+        &[(1, 0, 2), (1, 2, 4)],
+        &[(1, 3, 0)],
+        &[(1, 65535, 2)],
+    ];
+    for runs in bad_runs {
+        let compressed = CompressedPixelSet::new(raw_indexed(4, &[(0, runs)]));
+        assert_eq!(compressed.decompress().unwrap_err().kind(), InvalidData, "{:?}", runs);
+        assert_eq!(compressed.has(Pixel::new(0, 1)).unwrap_err().kind(), InvalidData, "{:?}", runs);
+    }
+
+    let outside_band = CompressedPixelSet::new(raw_indexed(4, &[(4, &[(9, 0, 1)])]));
+    assert_eq!(outside_band.decompress().unwrap_err().kind(), InvalidData);
+    assert_eq!(outside_band.rows(4..8).unwrap_err().kind(), InvalidData);
+}
+
+fn region_fixtures() -> Vec<(String, PixelSet)> {
+    (0..40u16)
+        .map(|i| {
+            let mut pixels = vec![];
+            for y in i..(i + 12) {
+                for x in (i * 3)..(i * 3 + 8 + (y % 4)) {
+                    pixels.push(Pixel::new(x, y));
+                }
+            }
+            (format!("region-{}", i), PixelSet::new(pixels))
+        })
+        .collect()
+}
+
+#[test]
+fn test_archive_roundtrip() {
+    let regions = region_fixtures();
+    let archive = PixelSetArchive::from_sets(regions.iter().map(|(n, s)| (n.as_str(), s))).unwrap();
+
+    assert_eq!(archive.len(), regions.len());
+    assert!(!archive.dictionary().is_empty(), "a shared dictionary should be trained");
+    let restored = PixelSetArchive::from_bytes(&archive.to_bytes()).unwrap();
+    assert_eq!(archive, restored);
+
+    for (name, set) in &regions {
+        let entry = restored.get(name).unwrap().expect("entry should exist");
+        entry.validate_invariants().expect("Archive entry has invalid invariants");
+        assert_eq!(&entry, set);
+    }
+    assert!(restored.get("missing").unwrap().is_none());
+}
+
+#[test]
+fn test_archive_insert_and_remove() {
+    let mut archive = PixelSetArchive::new();
+    let set = PixelSet::new(vec![Pixel::new(1, 1), Pixel::new(2, 1)]);
+
+    archive.insert("a", &set).unwrap();
+    archive.insert("b", &PixelSet::empty()).unwrap();
+    assert!(archive.contains("a"));
+    assert_eq!(archive.names().collect::<Vec<_>>(), vec!["a", "b"]);
+    assert_eq!(archive.get("a").unwrap(), Some(set));
+    assert_eq!(archive.get("b").unwrap(), Some(PixelSet::empty()));
+
+    assert!(archive.remove("a"));
+    assert!(!archive.remove("a"));
+    assert_eq!(archive.len(), 1);
+}
+
+#[test]
+fn test_archive_reader_reads_single_entry() {
+    let regions = region_fixtures();
+    let archive = PixelSetArchive::from_sets(regions.iter().map(|(n, s)| (n.clone(), s))).unwrap();
+    let bytes = archive.to_bytes();
+
+    let mut reader = ArchiveReader::new(std::io::Cursor::new(bytes)).unwrap();
+    assert_eq!(reader.len(), regions.len());
+    assert_eq!(reader.get("region-7").unwrap().as_ref(), Some(&regions[7].1));
+    assert_eq!(reader.get("region-0").unwrap().as_ref(), Some(&regions[0].1));
+    assert!(reader.get("missing").unwrap().is_none());
+}
+
+#[test]
+fn test_archive_json_serialization() {
+    let regions = region_fixtures();
+    let archive = PixelSetArchive::from_sets(regions.iter().take(3).map(|(n, s)| (n.as_str(), s))).unwrap();
+
+    let json = serde_json::to_string(&archive).unwrap();
+    assert!(json.starts_with('"') && json.ends_with('"'),
+            "PixelSetArchive should serialize as base64 string in JSON");
+
+    let deserialized: PixelSetArchive = serde_json::from_str(&json).unwrap();
+    assert_eq!(archive, deserialized);
+}
+
+#[test]
+fn test_archive_rejects_garbage() {
+    let result = PixelSetArchive::from_bytes(b"not an archive");
+    assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+
+    let mut truncated = PixelSetArchive::from_sets(
+        region_fixtures().iter().take(2).map(|(n, s)| (n.as_str(), s)),
+    ).unwrap().to_bytes();
+    truncated.truncate(truncated.len() - 3);
+    assert!(PixelSetArchive::from_bytes(&truncated).is_err());
+}
+
+/// Builds raw archive bytes without a dictionary from `(name, offset, length)` entries.
+fn raw_archive(entries: &[(&str, u64, u64)]) -> Vec<u8> {
+    let mut bytes = b"PXSA".to_vec();
+    bytes.extend_from_slice(&1u16.to_le_bytes());
+    bytes.extend_from_slice(&0u32.to_le_bytes());
+    bytes.extend_from_slice(&(entries.len() as u32).to_le_bytes());
+    for (name, offset, length) in entries {
+        bytes.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(name.as_bytes());
+        bytes.extend_from_slice(&offset.to_le_bytes());
+        bytes.extend_from_slice(&length.to_le_bytes());
+    }
+    bytes
+}
+
+#[test]
+fn test_archive_rejects_crafted_table_of_contents() {
+    let overflowing = raw_archive(&[("a", u64::MAX, 1)]);
+    let result = PixelSetArchive::from_bytes(&overflowing);
+    assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+
+    let duplicated = raw_archive(&[("a", 0, 0), ("a", 0, 0)]);
+    let result = PixelSetArchive::from_bytes(&duplicated);
+    assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+    let result = ArchiveReader::new(std::io::Cursor::new(duplicated));
+    assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_dictionary_compression_roundtrip() {
+    let regions = region_fixtures();
+    let dictionary = CompressionDictionary::train(regions.iter().map(|(_, s)| s), 4096).unwrap();
+    assert!(!dictionary.bytes().is_empty());
+
+    for (_, set) in &regions {
+        let compressed = set.compress_with_dictionary(&dictionary).unwrap();
+        assert_eq!(compressed.dictionary_id(), Some(dictionary.id()));
+
+        let recovered = compressed.decompress_with_dictionary(&dictionary).unwrap();
+        assert_eq!(&recovered, set);
+    }
+}
+
+#[test]
+fn test_dictionary_compression_detects_mismatch() {
+    let regions = region_fixtures();
+    let dictionary = CompressionDictionary::train(regions.iter().map(|(_, s)| s), 4096).unwrap();
+    let other = CompressionDictionary::from_bytes(vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_ne!(dictionary.id(), other.id());
+
+    let compressed = regions[0].1.compress_with_dictionary(&dictionary).unwrap();
+
+    let mismatch = compressed.decompress_with_dictionary(&other);
+    assert_eq!(mismatch.unwrap_err().kind(), std::io::ErrorKind::InvalidInput);
+
+    let missing = compressed.decompress();
+    assert_eq!(missing.unwrap_err().kind(), std::io::ErrorKind::InvalidInput);
+}
+
+#[test]
+fn test_dictionary_reloaded_from_bytes_keeps_id() {
+    let regions = region_fixtures();
+    let dictionary = CompressionDictionary::train(regions.iter().map(|(_, s)| s), 4096).unwrap();
+    let reloaded = CompressionDictionary::from_bytes(dictionary.bytes().to_vec());
+
+    assert_eq!(dictionary, reloaded);
+    let compressed = regions[3].1.compress_with_dictionary(&dictionary).unwrap();
+    assert_eq!(compressed.decompress_with_dictionary(&reloaded).unwrap(), regions[3].1);
+}
+
+#[test]
+fn test_plain_compression_ignores_dictionary() {
+    let set = PixelSet::new(vec![Pixel::new(3, 4)]);
+    let dictionary = CompressionDictionary::from_bytes(vec![0; 16]);
+
+    let compressed = set.compress().unwrap();
+    assert_eq!(compressed.dictionary_id(), None);
+    assert_eq!(compressed.decompress_with_dictionary(&dictionary).unwrap(), set);
+}
+
+#[test]
+fn test_archive_with_pretrained_dictionary() {
+    let regions = region_fixtures();
+    let dictionary = CompressionDictionary::train(regions.iter().map(|(_, s)| s), 4096).unwrap();
+
+    let mut archive = PixelSetArchive::with_dictionary(dictionary.clone());
+    archive.insert("first", &regions[0].1).unwrap();
+
+    let restored = PixelSetArchive::from_bytes(&archive.to_bytes()).unwrap();
+    assert_eq!(restored.compression_dictionary(), Some(&dictionary));
+    assert_eq!(restored.dictionary(), dictionary.bytes());
+    assert_eq!(restored.get("first").unwrap(), Some(regions[0].1.clone()));
+}
@@ -0,0 +1,84 @@
+use image::{DynamicImage, ImageBuffer, Luma, Rgba, RgbaImage};
+use pixelset::{Color, Pixel, PixelSet, Shape};
+use pixelset::color::Color16;
+use pixelset::shapes::Rectangle;
+
+fn numbered(width: u32, height: u32) -> RgbaImage {
+    ImageBuffer::from_fn(width, height, |x, y| Rgba([x as u8, y as u8, 100, 255]))
+}
+
+#[test]
+fn test_copy_to_same_format() {
+    let src = DynamicImage::ImageRgba8(numbered(8, 8));
+    let mut dst = DynamicImage::new_rgba8(8, 8);
+    let set = Rectangle { x: 1, y: 2, width: 3, height: 2 }.set();
+
+    set.copy_to(&src, &mut dst, (0, 0));
+    for y in 0..8 {
+        for x in 0..8 {
+            let pixel = Pixel::new(x, y);
+            let expected = if set.has(pixel) { pixel.color(&src) } else { Color::new(0, 0, 0, 0) };
+            assert_eq!(pixel.color(&dst), expected);
+        }
+    }
+}
+
+#[test]
+fn test_copy_to_with_offset_clips() {
+    let src = DynamicImage::ImageRgba8(numbered(8, 8));
+    let mut dst = DynamicImage::new_rgba8(4, 4);
+    let set = Rectangle { x: 0, y: 0, width: 8, height: 8 }.set();
+
+    set.copy_to(&src, &mut dst, (-2, 1));
+
+    assert_eq!(Pixel::new(0, 1).color(&dst), Color::new(2, 0, 100, 255));
+    assert_eq!(Pixel::new(3, 3).color(&dst), Color::new(5, 2, 100, 255));
+    assert_eq!(Pixel::new(0, 0).color(&dst), Color::new(0, 0, 0, 0));
+
+    // Entirely off-image copies are no-ops.
+    set.copy_to(&src, &mut dst, (100, 100));
+    set.copy_to(&src, &mut dst, (-100, 0));
+}
+
+#[test]
+fn test_copy_to_across_formats() {
+    let src = DynamicImage::ImageLuma16(ImageBuffer::from_fn(4, 1, |x, _| Luma([x as u16 * 1000 + 7])));
+    let mut dst = DynamicImage::new_rgba16(4, 1);
+    let set = PixelSet::new(vec![Pixel::new(1, 0), Pixel::new(3, 0)]);
+
+    set.copy_to(&src, &mut dst, (0, 0));
+
+    assert_eq!(Pixel::new(1, 0).color16(&dst), Color16::new(1007, 1007, 1007, 65535));
+    assert_eq!(Pixel::new(3, 0).color16(&dst), Color16::new(3007, 3007, 3007, 65535));
+    assert_eq!(Pixel::new(2, 0).color16(&dst), Color16::new(0, 0, 0, 0));
+}
+
+#[test]
+fn test_extract() {
+    let src = DynamicImage::ImageRgba8(numbered(10, 10));
+    let set = PixelSet::new(vec![Pixel::new(3, 4), Pixel::new(4, 4), Pixel::new(6, 5)]);
+
+    let out = set.extract(&src).unwrap();
+    assert_eq!(out.dimensions(), (4, 2));
+    assert_eq!(out.get_pixel(0, 0), &Rgba([3, 4, 100, 255]));
+    assert_eq!(out.get_pixel(1, 0), &Rgba([4, 4, 100, 255]));
+    assert_eq!(out.get_pixel(3, 1), &Rgba([6, 5, 100, 255]));
+    assert_eq!(out.get_pixel(2, 0), &Rgba([0, 0, 0, 0]));
+    assert_eq!(out.get_pixel(0, 1), &Rgba([0, 0, 0, 0]));
+
+    let rgb = DynamicImage::ImageRgb8(DynamicImage::ImageRgba8(numbered(10, 10)).to_rgb8());
+    assert_eq!(set.extract(&rgb).unwrap(), out);
+
+    assert!(PixelSet::empty().extract(&src).is_none());
+}
+
+#[test]
+fn test_extract_outside_image_is_transparent() {
+    let src = DynamicImage::ImageRgba8(numbered(4, 4));
+    let set = Rectangle { x: 2, y: 2, width: 4, height: 4 }.set();
+
+    let out = set.extract(&src).unwrap();
+    assert_eq!(out.dimensions(), (4, 4));
+    assert_eq!(out.get_pixel(1, 1), &Rgba([3, 3, 100, 255]));
+    assert_eq!(out.get_pixel(2, 2), &Rgba([0, 0, 0, 0]));
+}
@@ -0,0 +1,73 @@
+use image::{DynamicImage, ImageBuffer, Luma, Rgb, Rgba};
+use pixelset::{Color, Pixel, PixelSet};
+use pixelset::color::{Color16, ColorF32};
+
+fn row(width: u16) -> PixelSet {
+    PixelSet::new((0..width).map(|x| Pixel::new(x, 0)).collect())
+}
+
+#[test]
+fn test_color16_conversions() {
+    let color = Color::new(0, 1, 128, 255);
+    let deep = Color16::from(color);
+    assert_eq!(deep, Color16::new(0, 257, 32896, 65535));
+    assert_eq!(Color::from(deep), color);
+
+    assert_eq!(Color::from(Color16::new(384, 386, 65535, 0)), Color::new(1, 2, 255, 0));
+    assert_eq!(ColorF32::from(Color16::new(0, 65535, 0, 65535)), ColorF32::new(0.0, 1.0, 0.0, 1.0));
+    assert_eq!(Color16::from(ColorF32::new(-1.0, 0.5, 2.0, 1.0)), Color16::new(0, 32768, 65535, 65535));
+    assert_eq!(Color::from(ColorF32::new(0.2, 0.4, 0.6, 1.0)), Color::new(51, 102, 153, 255));
+}
+
+#[test]
+fn test_rgba16_round_trip_is_lossless() {
+    let buf = ImageBuffer::from_fn(4, 1, |x, _| Rgba([x as u16 * 1000 + 1, 2, 65534, 40000]));
+    let mut image = DynamicImage::ImageRgba16(buf);
+    let set = row(4);
+
+    let colors: Vec<Color16> = set.as_colors16(&image).collect();
+    assert_eq!(colors[3], Color16::new(3001, 2, 65534, 40000));
+
+    set.transform16(&mut image, |c| Color16::new(c.r() + 1, c.g(), c.b(), c.a()));
+    assert_eq!(Pixel::new(3, 0).color16(&image), Color16::new(3002, 2, 65534, 40000));
+    assert_eq!(Pixel::new(0, 0).color16(&image), Color16::new(2, 2, 65534, 40000));
+    assert_eq!(Pixel::new(0, 0).color(&image).r(), 0);
+}
+
+#[test]
+fn test_rgb32f_round_trip_is_lossless() {
+    let buf = ImageBuffer::from_pixel(3, 1, Rgb([0.123_456_7f32, 1.5, -0.25]));
+    let mut image = DynamicImage::ImageRgb32F(buf);
+    let set = row(3);
+
+    assert_eq!(Pixel::new(1, 0).color_f32(&image), ColorF32::new(0.123_456_7, 1.5, -0.25, 1.0));
+
+    set.transform_f32(&mut image, |c| ColorF32::new(c.r() * 2.0, c.g(), c.b(), c.a()));
+    assert_eq!(Pixel::new(2, 0).color_f32(&image), ColorF32::new(0.246_913_4, 1.5, -0.25, 1.0));
+
+    set.fill_f32(&mut image, ColorF32::new(4.0, 0.0, 0.5, 1.0));
+    let colors: Vec<ColorF32> = set.as_colors_f32(&image).collect();
+    assert!(colors.iter().all(|&c| c == ColorF32::new(4.0, 0.0, 0.5, 1.0)));
+}
+
+#[test]
+fn test_luma16_stores_luma() {
+    let mut image = DynamicImage::ImageLuma16(ImageBuffer::from_pixel(2, 1, Luma([0u16])));
+    let set = row(2);
+
+    set.fill16(&mut image, Color16::new(12345, 12345, 12345, 65535));
+    assert_eq!(Pixel::new(1, 0).color16(&image), Color16::new(12345, 12345, 12345, 65535));
+}
+
+#[test]
+fn test_deep_ops_on_8bit_images() {
+    let mut image = DynamicImage::new_rgba8(2, 1);
+    let set = row(2);
+
+    set.fill16(&mut image, Color16::new(257, 514, 65535, 65535));
+    assert_eq!(Pixel::new(0, 0).color(&image), Color::new(1, 2, 255, 255));
+    assert_eq!(Pixel::new(0, 0).color16(&image), Color16::new(257, 514, 65535, 65535));
+
+    set.fill_f32(&mut image, ColorF32::new(1.0, 0.0, 0.0, 1.0));
+    assert_eq!(Pixel::new(1, 0).color(&image), Color::new(255, 0, 0, 255));
+}
@@ -0,0 +1,227 @@
+use image::{DynamicImage, ImageBuffer, Rgba, RgbaImage};
+use pixelset::{Color, Direction, Pixel, PixelSet, Shape};
+use pixelset::paint::Paint;
+use pixelset::shapes::{Ellipse, EllipseOutline, Line, Rectangle, Triangle};
+
+const BLACK: Color = Color::new(0, 0, 0, 255);
+const WHITE: Color = Color::new(255, 255, 255, 255);
+
+fn rect(x: u16, y: u16, width: u16, height: u16) -> PixelSet {
+    Rectangle { x, y, width, height }.set()
+}
+
+#[test]
+fn test_fill_with_solid_matches_fill() {
+    let mut a = DynamicImage::new_rgba8(8, 8);
+    let mut b = DynamicImage::new_rgb8(8, 8);
+    let set = rect(1, 2, 5, 3);
+
+    set.fill_with(&mut a, Paint::Solid(WHITE));
+    set.fill_with(&mut b, Paint::Solid(WHITE));
+
+    let mut expected_a = DynamicImage::new_rgba8(8, 8);
+    let mut expected_b = DynamicImage::new_rgb8(8, 8);
+    set.fill(&mut expected_a, WHITE);
+    set.fill(&mut expected_b, WHITE);
+    assert_eq!(a, expected_a);
+    assert_eq!(b, expected_b);
+}
+
+#[test]
+fn test_linear_gradient_spans_set() {
+    let mut image = DynamicImage::new_rgba8(16, 4);
+    let set = rect(2, 0, 11, 4);
+
+    set.fill_with(&mut image, Paint::LinearGradient {
+        direction: Direction::new(1.0, 0.0).unwrap(),
+        from: BLACK,
+        to: WHITE,
+    });
+
+    assert_eq!(Pixel::new(2, 1).color(&image), BLACK);
+    assert_eq!(Pixel::new(12, 1).color(&image), WHITE);
+    assert_eq!(Pixel::new(7, 3).color(&image), Color::new(128, 128, 128, 255));
+    assert_eq!(Pixel::new(0, 0).color(&image), Color::new(0, 0, 0, 0));
+
+    let reds: Vec<u8> = (2..13).map(|x| Pixel::new(x, 0).color(&image).r()).collect();
+    assert!(reds.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn test_radial_gradient_from_centroid() {
+    let mut image = DynamicImage::new_rgba8(9, 9);
+    let set = rect(0, 0, 9, 9);
+
+    set.fill_with(&mut image, Paint::RadialGradient { from: WHITE, to: BLACK });
+
+    assert_eq!(Pixel::new(4, 4).color(&image), WHITE);
+    assert_eq!(Pixel::new(0, 0).color(&image), BLACK);
+    assert_eq!(Pixel::new(8, 8).color(&image), BLACK);
+    assert_eq!(Pixel::new(4, 0).color(&image), Pixel::new(0, 4).color(&image));
+    assert!(Pixel::new(4, 0).color(&image).r() > 0);
+}
+
+#[test]
+fn test_pattern_tiles_from_origin() {
+    let pattern: RgbaImage = ImageBuffer::from_fn(2, 2, |x, y| Rgba([(x * 100) as u8, (y * 100) as u8, 0, 255]));
+    let mut image = DynamicImage::new_rgba8(6, 6);
+    let set = rect(1, 1, 4, 4);
+
+    set.fill_with(&mut image, Paint::Pattern(&pattern));
+
+    for pixel in &set {
+        let expected: Color = pattern.get_pixel(pixel.x as u32 % 2, pixel.y as u32 % 2).0.into();
+        assert_eq!(pixel.color(&image), expected);
+    }
+    assert_eq!(Pixel::new(0, 0).color(&image), Color::new(0, 0, 0, 0));
+}
+
+#[test]
+fn test_hatch_stripes() {
+    let mut image = DynamicImage::ImageRgba8(ImageBuffer::from_pixel(8, 8, Rgba([1, 2, 3, 255])));
+    let set = rect(0, 0, 8, 8);
+
+    set.fill_with(&mut image, Paint::Hatch {
+        direction: Direction::new(1.0, 0.0).unwrap(),
+        spacing: 4,
+        width: 1,
+        color: BLACK,
+        background: None,
+    });
+
+    for y in 0..8 {
+        let expected = if y % 4 == 0 { BLACK } else { Color::new(1, 2, 3, 255) };
+        for x in 0..8 {
+            assert_eq!(Pixel::new(x, y).color(&image), expected, "at ({}, {})", x, y);
+        }
+    }
+
+    set.fill_with(&mut image, Paint::Hatch {
+        direction: Direction::new(0.0, 1.0).unwrap(),
+        spacing: 2,
+        width: 1,
+        color: BLACK,
+        background: Some(WHITE),
+    });
+    assert_ne!(Pixel::new(0, 3).color(&image), Pixel::new(1, 3).color(&image));
+    assert_eq!(Pixel::new(0, 3).color(&image), Pixel::new(2, 5).color(&image));
+}
+
+#[test]
+fn test_checkerboard() {
+    let mut image = DynamicImage::new_rgb8(8, 8);
+    let set = rect(0, 0, 8, 8);
+
+    set.fill_with(&mut image, Paint::Checkerboard { size: 2, colors: [BLACK, WHITE] });
+
+    assert_eq!(Pixel::new(0, 0).color(&image), BLACK);
+    assert_eq!(Pixel::new(1, 1).color(&image), BLACK);
+    assert_eq!(Pixel::new(2, 0).color(&image), WHITE);
+    assert_eq!(Pixel::new(2, 2).color(&image), BLACK);
+    assert_eq!(Pixel::new(7, 0).color(&image), WHITE);
+}
+
+#[test]
+fn test_checkerboard_at_coordinate_limit() {
+    let mut image = DynamicImage::new_rgb8(65536, 2);
+    let set = PixelSet::new(vec![Pixel::new(u16::MAX, 0), Pixel::new(u16::MAX, 1)]);
+
+    set.fill_with(&mut image, Paint::Checkerboard { size: 1, colors: [BLACK, WHITE] });
+    assert_eq!(Pixel::new(u16::MAX, 0).color(&image), WHITE);
+    assert_eq!(Pixel::new(u16::MAX, 1).color(&image), BLACK);
+}
+
+#[test]
+fn test_fill_with_skips_pixels_outside_image() {
+    let mut image = DynamicImage::new_rgba8(4, 4);
+    let set = rect(2, 2, 10, 10);
+
+    set.fill_with(&mut image, Paint::Checkerboard { size: 1, colors: [BLACK, WHITE] });
+    assert_eq!(Pixel::new(3, 3).color(&image), BLACK);
+    assert_eq!(Pixel::new(1, 1).color(&image), Color::new(0, 0, 0, 0));
+}
+
+#[test]
+fn test_fill_antialiased() {
+    let mut image = DynamicImage::ImageRgba8(ImageBuffer::from_pixel(16, 16, Rgba([255, 255, 255, 255])));
+    let circle = Ellipse { x: 2, y: 2, width: 12, height: 12 };
+    PixelSet::from_image(&image).fill_antialiased(&mut image, &circle, BLACK);
+
+    assert_eq!(Pixel::new(8, 8).color(&image), BLACK);
+    assert_eq!(Pixel::new(0, 0).color(&image), WHITE);
+
+    // Edge pixels are blended, and the darker the more of them the circle covers.
+    let edge = Pixel::new(3, 4).color(&image);
+    assert!(edge != BLACK && edge != WHITE && edge.a() == 255, "{:?}", edge);
+    assert!(Pixel::new(3, 5).color(&image).r() < edge.r());
+}
+
+/// Checks that `fill_antialiased` blends every pixel by the coverage its own signed
+/// distance gives.
+fn assert_fills_by_distance(shape: &impl Shape) {
+    let mut image = DynamicImage::ImageRgba8(ImageBuffer::from_pixel(32, 32, Rgba([255, 255, 255, 255])));
+    let mut expected = image.clone();
+    PixelSet::from_image(&image).fill_antialiased(&mut image, shape, BLACK);
+
+    for pixel in &PixelSet::from_image(&expected) {
+        let distance = shape.signed_distance(pixel.x as f32 + 0.5, pixel.y as f32 + 0.5);
+        let opacity = ((0.5 - distance).clamp(0.0, 1.0) * 255.0).round() as u8;
+        if opacity > 0 {
+            let blended = pixel.color(&expected).blend(BLACK, opacity);
+            pixel.set(&mut expected, blended);
+        }
+    }
+    assert_eq!(image, expected);
+}
+
+#[test]
+fn test_fill_antialiased_measures_only_near_edges() {
+    assert_fills_by_distance(&Ellipse { x: 3, y: 2, width: 25, height: 19 });
+    assert_fills_by_distance(&EllipseOutline { x: 3, y: 2, width: 25, height: 19, stroke: 3 });
+    assert_fills_by_distance(&Line::new(Pixel::new(1, 30), Pixel::new(29, 3)).with_width(4));
+    assert_fills_by_distance(&Triangle { a: Pixel::new(0, 0), b: Pixel::new(31, 9), c: Pixel::new(12, 40) });
+}
+
+#[test]
+fn test_fill_antialiased_large_shape_without_exact_distance() {
+    let mut image = DynamicImage::new_rgba8(1200, 1200);
+    let triangle = Triangle { a: Pixel::new(10, 20), b: Pixel::new(1190, 600), c: Pixel::new(300, 1180) };
+    PixelSet::from_image(&image).fill_antialiased(&mut image, &triangle, BLACK);
+
+    // Distances to the edge of whole pixels never land between them, so the fill is exact.
+    let mut expected = DynamicImage::new_rgba8(1200, 1200);
+    triangle.set().fill(&mut expected, BLACK);
+    assert_eq!(image, expected);
+}
+
+#[test]
+fn test_fill_antialiased_within_set() {
+    let mut image = DynamicImage::new_rgba8(8, 8);
+    let square = Rectangle { x: 2, y: 2, width: 4, height: 4 };
+    rect(0, 0, 4, 8).fill_antialiased(&mut image, &square, BLACK);
+
+    // Whole-pixel edges need no blending, and only the set is painted.
+    let mut expected = DynamicImage::new_rgba8(8, 8);
+    rect(2, 2, 2, 4).fill(&mut expected, BLACK);
+    assert_eq!(image, expected);
+
+    // Shapes crossing the image edge are clipped to it.
+    let mut image = DynamicImage::new_rgba8(4, 4);
+    PixelSet::from_image(&image).fill_antialiased(&mut image, &Ellipse { x: 1, y: 1, width: 9, height: 9 }, BLACK);
+    assert_eq!(Pixel::new(3, 3).color(&image), BLACK);
+}
+
+#[test]
+fn test_fill_antialiased_taller_than_coordinates() {
+    let white = |height| DynamicImage::ImageRgba8(ImageBuffer::from_pixel(1, height, Rgba([255, 255, 255, 255])));
+    let corner = Ellipse { x: 0, y: 0, width: 2, height: 2 };
+    let mut expected = white(2);
+    PixelSet::from_image(&expected).fill_antialiased(&mut expected, &corner, BLACK);
+
+    // Reaching the last row must not wrap around and blend the first one a second time.
+    let mut image = white(u16::MAX as u32 + 2);
+    let shape = corner.union(Rectangle { x: 0, y: u16::MAX, width: 1, height: 1 });
+    PixelSet::from_image(&image).fill_antialiased(&mut image, &shape, BLACK);
+    assert_eq!(Pixel::new(0, 0).color(&image), Pixel::new(0, 0).color(&expected));
+    assert_eq!(Pixel::new(0, u16::MAX).color(&image), BLACK);
+}
@@ -51,6 +51,16 @@ fn test_add_duplicate() {
     assert_eq!(set.len(), 2, "Duplicate should be ignored");
 }
 
+#[test]
+fn test_add_duplicate_inside_run() {
+    let mut set = PixelSet::new(vec![Pixel::new(0, 0), Pixel::new(1, 0), Pixel::new(2, 0)]);
+    set.add(Pixel::new(1, 0));
+    set.add(Pixel::new(2, 0));
+
+    set.validate_invariants().expect("ADD result has invalid invariants");
+    assert_eq!(set.len(), 3, "Pixel inside an existing run should be ignored");
+}
+
 #[test]
 fn test_discard_from_middle() {
     let mut set = PixelSet::new(vec![
@@ -0,0 +1,88 @@
+#![cfg(feature = "rand")]
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use pixelset::{Color, Pixel, PixelSet};
+use pixelset::color::ColorMetric;
+
+fn sample_set() -> PixelSet {
+    let mut pixels = Vec::new();
+    for x in 0..10 {
+        pixels.push(Pixel::new(x, 0));
+    }
+    pixels.push(Pixel::new(3, 5));
+    for x in 100..104 {
+        pixels.push(Pixel::new(x, 7));
+    }
+    PixelSet::new(pixels)
+}
+
+#[test]
+fn test_random_color_is_opaque() {
+    let mut rng = StdRng::seed_from_u64(7);
+    for _ in 0..100 {
+        assert!(Color::random(&mut rng).is_opaque());
+    }
+}
+
+#[test]
+fn test_distinct_palette_separates_hues() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let palette = Color::distinct_palette(6, &mut rng);
+    assert_eq!(palette.len(), 6);
+
+    for (i, a) in palette.iter().enumerate() {
+        for b in &palette[i + 1..] {
+            assert!(a.distance(*b, ColorMetric::Euclidean) > 100.0, "{} and {} too close", a, b);
+        }
+    }
+
+    let hues: Vec<f32> = palette.iter().map(|c| c.to_hsl().h).collect();
+    let gap = (hues[1] - hues[0]).rem_euclid(360.0);
+    assert!((gap - 60.0).abs() < 2.0);
+
+    assert!(Color::distinct_palette(0, &mut rng).is_empty());
+}
+
+#[test]
+fn test_random_pixel_in_set() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let set = sample_set();
+
+    let mut seen = PixelSet::empty();
+    for _ in 0..2000 {
+        let pixel = set.random_pixel(&mut rng).unwrap();
+        assert!(set.has(pixel));
+        seen.add(pixel);
+    }
+    assert_eq!(seen, set);
+
+    assert_eq!(PixelSet::empty().random_pixel(&mut rng), None);
+}
+
+#[test]
+fn test_random_pixel_is_uniform() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let set = sample_set();
+    let lone = Pixel::new(3, 5);
+
+    let draws = 15_000;
+    let hits = (0..draws).filter(|_| set.random_pixel(&mut rng) == Some(lone)).count();
+    let expected = draws / set.len();
+    assert!(hits.abs_diff(expected) < expected / 4, "{} hits, expected about {}", hits, expected);
+}
+
+#[test]
+fn test_sample() {
+    let mut rng = StdRng::seed_from_u64(9);
+    let set = sample_set();
+
+    let sample = set.sample(5, &mut rng);
+    assert_eq!(sample.len(), 5);
+    assert!(sample.is_subset(&set));
+
+    assert_eq!(set.sample(set.len(), &mut rng), set);
+    assert_eq!(set.sample(100, &mut rng), set);
+    assert!(set.sample(0, &mut rng).is_empty());
+    assert!(PixelSet::empty().sample(3, &mut rng).is_empty());
+}
@@ -0,0 +1,141 @@
+use pixelset::{Direction, LineOfSight, Pixel, PixelSet, Shape};
+use pixelset::shapes::{Ellipse, EllipseOutline, Rectangle};
+
+fn rect(x: u16, y: u16, width: u16, height: u16) -> PixelSet {
+    Rectangle { x, y, width, height }.set()
+}
+
+/// The first pixel of the set under a ray, found by marching along it in tiny steps.
+fn march(set: &PixelSet, origin: Pixel, dir: Direction, max_len: f32) -> Option<Pixel> {
+    let (ox, oy) = (origin.x as f32 + 0.5, origin.y as f32 + 0.5);
+    let steps = (max_len / 0.001) as u32;
+    (0..=steps).map(|i| i as f32 * 0.001).find_map(|t| {
+        let (x, y) = (ox + dir.dx * t, oy + dir.dy * t);
+        let pixel = (x >= 0.0 && y >= 0.0).then(|| Pixel::new(x as u16, y as u16))?;
+        set.has(pixel).then_some(pixel)
+    })
+}
+
+#[test]
+fn test_raycast_axis_aligned() {
+    let wall = rect(10, 0, 3, 20);
+    let east = Direction::new(1.0, 0.0).unwrap();
+
+    assert_eq!(wall.raycast(Pixel::new(2, 5), east, 100.0), Some((Pixel::new(10, 5), 7.5)));
+    assert_eq!(wall.raycast(Pixel::new(20, 5), east.opposite(), 100.0), Some((Pixel::new(12, 5), 7.5)));
+    assert_eq!(wall.raycast(Pixel::new(2, 5), east, 7.0), None);
+    assert_eq!(wall.raycast(Pixel::new(20, 5), east, 100.0), None);
+
+    let floor = rect(0, 15, 20, 2);
+    let south = Direction::new(0.0, 1.0).unwrap();
+    assert_eq!(floor.raycast(Pixel::new(4, 3), south, 100.0), Some((Pixel::new(4, 15), 11.5)));
+    assert_eq!(floor.raycast(Pixel::new(4, 30), south.opposite(), 100.0), Some((Pixel::new(4, 16), 13.5)));
+    assert_eq!(floor.raycast(Pixel::new(4, 30), south, 100.0), None);
+}
+
+#[test]
+fn test_raycast_origin_inside() {
+    let wall = rect(10, 0, 3, 20);
+    let dir = Direction::from_angle(1.0);
+    assert_eq!(wall.raycast(Pixel::new(11, 5), dir, 10.0), Some((Pixel::new(11, 5), 0.0)));
+    assert_eq!(PixelSet::empty().raycast(Pixel::new(11, 5), dir, 10.0), None);
+}
+
+#[test]
+fn test_raycast_diagonal() {
+    let target = PixelSet::new(vec![Pixel::new(5, 5)]);
+    let dir = Direction::new(1.0, 1.0).unwrap();
+    let (pixel, distance) = target.raycast(Pixel::new(0, 0), dir, 100.0).unwrap();
+    assert_eq!(pixel, Pixel::new(5, 5));
+    assert!((distance - 4.5 * 2f32.sqrt()).abs() < 1e-4);
+
+    // Passing exactly through pixel corners skips the pixels beside them.
+    let beside = PixelSet::new(vec![Pixel::new(1, 0), Pixel::new(0, 1)]);
+    assert_eq!(beside.raycast(Pixel::new(0, 0), dir, 100.0), None);
+}
+
+#[test]
+fn test_raycast_long_diagonal_through_corners() {
+    let dir = Direction::new(1.0, 1.0).unwrap();
+    let beside = |i: u16| PixelSet::new(vec![Pixel::new(i + 1, i), Pixel::new(i, i + 1)]);
+    assert_eq!(beside(25).raycast(Pixel::new(0, 0), dir, f32::INFINITY), None);
+
+    // Rounding along the ray must not let it clip the pixels beside any corner it passes.
+    let steps = (0..u16::MAX - 1).step_by(997).chain([u16::MAX - 2]);
+    let staircase = PixelSet::new(steps.clone().flat_map(|i| beside(i).iter().collect::<Vec<_>>()).collect());
+    assert_eq!(staircase.raycast(Pixel::new(0, 0), dir, f32::INFINITY), None);
+    assert_eq!(staircase.raycast(Pixel::new(u16::MAX, u16::MAX), dir.opposite(), f32::INFINITY), None);
+    assert_eq!(staircase.line_of_sight(Pixel::new(0, 0), Pixel::new(u16::MAX, u16::MAX)), LineOfSight::Outside);
+
+    for i in steps {
+        let target = PixelSet::new(vec![Pixel::new(i, i)]).or(&beside(i));
+        let (pixel, distance) = target.raycast(Pixel::new(0, 0), dir, f32::INFINITY).unwrap();
+        assert_eq!(pixel, Pixel::new(i, i));
+        assert!((distance - (i as f32 - 0.5).max(0.0) * 2f32.sqrt()).abs() < 1e-2, "{} at {}", distance, i);
+    }
+}
+
+#[test]
+fn test_raycast_matches_marching() {
+    let ring = EllipseOutline { x: 10, y: 10, width: 40, height: 30, stroke: 2 }.set();
+    let blob = Ellipse { x: 25, y: 20, width: 8, height: 6 }.set();
+    let set = ring.or(&blob);
+
+    for origin in [Pixel::new(30, 25), Pixel::new(2, 3), Pixel::new(60, 40), Pixel::new(14, 22)] {
+        for i in 0..40 {
+            let dir = Direction::from_angle(0.1 + i as f32 * 0.157);
+            let expected = march(&set, origin, dir, 80.0);
+            let hit = set.raycast(origin, dir, 80.0);
+            assert_eq!(hit.map(|(pixel, _)| pixel), expected, "from {:?} at {}", origin, dir.angle());
+
+            if let Some((pixel, distance)) = hit {
+                // The ray enters the hit pixel at the reported distance.
+                let (x, y) = (origin.x as f32 + 0.5 + dir.dx * distance, origin.y as f32 + 0.5 + dir.dy * distance);
+                assert!(x >= pixel.x as f32 - 1e-3 && x <= pixel.x as f32 + 1.0 + 1e-3);
+                assert!(y >= pixel.y as f32 - 1e-3 && y <= pixel.y as f32 + 1.0 + 1e-3);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_raycast_coordinate_limit() {
+    let edge = rect(u16::MAX - 1, 0, 2, 4);
+    let east = Direction::new(1.0, 0.0).unwrap();
+    assert_eq!(edge.raycast(Pixel::new(u16::MAX - 5, 2), east, f32::INFINITY), Some((Pixel::new(u16::MAX - 1, 2), 3.5)));
+    assert_eq!(edge.raycast(Pixel::new(u16::MAX, 10), east, f32::INFINITY), None);
+
+    let north = Direction::new(0.0, -1.0).unwrap();
+    assert_eq!(rect(0, 0, 4, 1).raycast(Pixel::new(2, 9), north, f32::INFINITY), Some((Pixel::new(2, 0), 8.5)));
+    assert_eq!(rect(0, 5, 4, 1).raycast(Pixel::new(2, 3), north, f32::INFINITY), None);
+}
+
+#[test]
+fn test_line_of_sight() {
+    let room = rect(0, 0, 20, 20).difference(&rect(10, 0, 1, 15));
+
+    assert_eq!(room.line_of_sight(Pixel::new(2, 2), Pixel::new(8, 12)), LineOfSight::Inside);
+    assert_eq!(room.line_of_sight(Pixel::new(2, 2), Pixel::new(15, 2)), LineOfSight::Crosses(Pixel::new(10, 2)));
+    assert_eq!(room.line_of_sight(Pixel::new(15, 2), Pixel::new(2, 2)), LineOfSight::Crosses(Pixel::new(10, 2)));
+    assert_eq!(room.line_of_sight(Pixel::new(2, 18), Pixel::new(17, 18)), LineOfSight::Inside);
+    assert_eq!(room.line_of_sight(Pixel::new(5, 5), Pixel::new(25, 5)), LineOfSight::Crosses(Pixel::new(10, 5)));
+
+    let wall = rect(10, 0, 1, 15);
+    assert_eq!(wall.line_of_sight(Pixel::new(2, 2), Pixel::new(8, 12)), LineOfSight::Outside);
+    assert_eq!(wall.line_of_sight(Pixel::new(10, 2), Pixel::new(10, 14)), LineOfSight::Inside);
+    assert_eq!(wall.line_of_sight(Pixel::new(10, 2), Pixel::new(10, 20)), LineOfSight::Crosses(Pixel::new(10, 15)));
+
+    // A segment of one pixel only looks at that pixel.
+    assert_eq!(wall.line_of_sight(Pixel::new(10, 3), Pixel::new(10, 3)), LineOfSight::Inside);
+    assert_eq!(wall.line_of_sight(Pixel::new(3, 3), Pixel::new(3, 3)), LineOfSight::Outside);
+}
+
+#[test]
+fn test_line_of_sight_is_symmetric_for_convex_sets() {
+    let disc = Ellipse { x: 0, y: 0, width: 30, height: 30 }.set();
+    for (a, b) in [((5, 8), (25, 20)), ((15, 1), (14, 28)), ((3, 15), (27, 16))] {
+        let (a, b) = (Pixel::new(a.0, a.1), Pixel::new(b.0, b.1));
+        assert_eq!(disc.line_of_sight(a, b), LineOfSight::Inside);
+        assert_eq!(disc.line_of_sight(b, a), LineOfSight::Inside);
+    }
+}
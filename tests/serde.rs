@@ -0,0 +1,107 @@
+use pixelset::{Pixel, PixelSet, Shape};
+use pixelset::shapes::Rectangle;
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+struct ReadableRegions {
+    #[serde(with = "pixelset::serde::runs")]
+    runs: PixelSet,
+    #[serde(with = "pixelset::serde::rects")]
+    rects: PixelSet,
+    #[serde(with = "pixelset::serde::pixels")]
+    pixels: PixelSet,
+}
+
+fn l_shape() -> PixelSet {
+    let mut pixels = vec![];
+    for y in 0..3 {
+        for x in 2..5 {
+            pixels.push(Pixel::new(x, y));
+        }
+    }
+    pixels.push(Pixel::new(2, 3));
+    PixelSet::new(pixels)
+}
+
+#[test]
+fn test_serde_adapters_roundtrip() {
+    let set = l_shape();
+    let regions = ReadableRegions { runs: set.clone(), rects: set.clone(), pixels: set };
+
+    let json = serde_json::to_string(&regions).unwrap();
+    let deserialized: ReadableRegions = serde_json::from_str(&json).unwrap();
+    assert_eq!(regions, deserialized);
+}
+
+#[test]
+fn test_serde_runs_format() {
+    let set = PixelSet::new(vec![Pixel::new(2, 0), Pixel::new(3, 0), Pixel::new(7, 1)]);
+    let value = serde_json::to_value(ReadableRegions {
+        runs: set.clone(), rects: PixelSet::empty(), pixels: PixelSet::empty(),
+    }).unwrap();
+
+    assert_eq!(value["runs"], serde_json::json!([
+        { "y": 0, "x": 2, "length": 2 },
+        { "y": 1, "x": 7, "length": 1 },
+    ]));
+}
+
+#[test]
+fn test_serde_rects_merges_stacked_runs() {
+    let value = serde_json::to_value(ReadableRegions {
+        runs: PixelSet::empty(), rects: l_shape(), pixels: PixelSet::empty(),
+    }).unwrap();
+
+    assert_eq!(value["rects"], serde_json::json!([
+        { "x": 2, "y": 0, "width": 3, "height": 3 },
+        { "x": 2, "y": 3, "width": 1, "height": 1 },
+    ]));
+}
+
+#[test]
+fn test_serde_pixels_format() {
+    let value = serde_json::to_value(ReadableRegions {
+        runs: PixelSet::empty(), rects: PixelSet::empty(), pixels: PixelSet::new(vec![Pixel::new(4, 1)]),
+    }).unwrap();
+
+    assert_eq!(value["pixels"], serde_json::json!([{ "y": 1, "x": 4 }]));
+}
+
+#[test]
+fn test_serde_adapters_normalize_overlapping_input() {
+    let json = r#"{
+        "runs": [{ "y": 1, "x": 4, "length": 3 }, { "y": 0, "x": 0, "length": 2 }, { "y": 1, "x": 0, "length": 5 }],
+        "rects": [{ "x": 0, "y": 0, "width": 2, "height": 2 }, { "x": 1, "y": 1, "width": 2, "height": 1 }],
+        "pixels": [{ "y": 0, "x": 1 }, { "y": 0, "x": 0 }, { "y": 0, "x": 1 }]
+    }"#;
+    let regions: ReadableRegions = serde_json::from_str(json).unwrap();
+
+    regions.runs.validate_invariants().expect("Deserialized runs have invalid invariants");
+    regions.rects.validate_invariants().expect("Deserialized rects have invalid invariants");
+    regions.pixels.validate_invariants().expect("Deserialized pixels have invalid invariants");
+    assert_eq!(regions.runs.len(), 9);
+    assert_eq!(regions.rects.len(), 5);
+    assert_eq!(regions.pixels.len(), 2);
+}
+
+#[test]
+fn test_serde_runs_rejects_out_of_range() {
+    let json = r#"{ "runs": [{ "y": 0, "x": 65535, "length": 2 }], "rects": [], "pixels": [] }"#;
+    assert!(serde_json::from_str::<ReadableRegions>(json).is_err());
+}
+
+#[test]
+fn test_serde_rects_full_height_column() {
+    let column = Rectangle { x: 7, y: 0, width: 2, height: u16::MAX }.set()
+        .or(&Rectangle { x: 7, y: u16::MAX, width: 2, height: 1 }.set());
+    let value = serde_json::to_value(ReadableRegions {
+        runs: PixelSet::empty(), rects: column.clone(), pixels: PixelSet::empty(),
+    }).unwrap();
+
+    // A rectangle holds at most 65535 rows, so the last row gets its own.
+    assert_eq!(value["rects"], serde_json::json!([
+        { "x": 7, "y": 0, "width": 2, "height": 65535 },
+        { "x": 7, "y": 65535, "width": 2, "height": 1 },
+    ]));
+    let regions: ReadableRegions = serde_json::from_value(value).unwrap();
+    assert_eq!(regions.rects, column);
+}
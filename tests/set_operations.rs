@@ -224,6 +224,20 @@ fn test_is_subset_false() {
     assert!(!set_a.is_subset(&set_b));
 }
 
+#[test]
+fn test_is_subset_multiple_runs_per_row() {
+    let set_a = PixelSet::new(vec![
+        Pixel::new(1, 0), Pixel::new(5, 0), Pixel::new(6, 0), Pixel::new(2, 1),
+    ]);
+    let set_b = PixelSet::new(vec![
+        Pixel::new(0, 0), Pixel::new(1, 0), Pixel::new(5, 0), Pixel::new(6, 0),
+        Pixel::new(7, 0), Pixel::new(2, 1),
+    ]);
+
+    assert!(set_a.is_subset(&set_b));
+    assert!(!set_b.is_subset(&set_a));
+}
+
 #[test]
 fn test_intersects_true() {
     let set_a = PixelSet::new(vec![
@@ -294,3 +308,51 @@ fn test_all_operations_maintain_invariants() {
     set_a.xor(&set_b).validate_invariants().expect("XOR should maintain invariants");
     set_a.difference(&set_b).validate_invariants().expect("DIFFERENCE should maintain invariants");
 }
+
+#[test]
+fn test_xor_multiple_runs_per_row() {
+    let set_a = PixelSet::new((0..3).chain(10..13).map(|x| Pixel::new(x, 0)).collect());
+    let set_b = PixelSet::new((1..12).map(|x| Pixel::new(x, 0)).collect());
+
+    let result = set_a.xor(&set_b);
+    result.validate_invariants().expect("XOR with several runs per row has invalid invariants");
+    let xs: Vec<_> = result.iter().map(|p| p.x).collect();
+    assert_eq!(xs, vec![0, 3, 4, 5, 6, 7, 8, 9, 12]);
+}
+
+#[test]
+fn test_operations_on_full_width_rows() {
+    let row = |xs: std::ops::RangeInclusive<u16>| PixelSet::new(xs.map(|x| Pixel::new(x, 3)).collect());
+    let full = row(0..=u16::MAX);
+    let partial = row(3..=u16::MAX);
+    let dot = row(5..=5);
+
+    for result in [full.or(&full), full.or(&partial), full.and(&full), partial.or(&row(0..=2))] {
+        result.validate_invariants().expect("Full-width row has invalid invariants");
+        assert_eq!(result, full);
+    }
+    assert_eq!(full.and(&partial), partial);
+    assert_eq!(full.xor(&partial), row(0..=2));
+    assert!(full.xor(&full).is_empty());
+
+    let holed = full.difference(&dot);
+    holed.validate_invariants().expect("Full-width row with a hole has invalid invariants");
+    assert_eq!(holed.len(), 65535);
+    assert_eq!(holed.or(&dot), full);
+
+    assert!(partial.is_subset(&full) && full.is_subset(&full));
+    assert!(!full.is_subset(&partial) && !full.is_subset(&holed));
+    assert!(full.intersects(&dot));
+
+    for x in [0, 5, u16::MAX - 1, u16::MAX] {
+        let mut set = full.clone();
+        set.discard(Pixel::new(x, 3));
+        set.validate_invariants().expect("Discarding from a full-width row has invalid invariants");
+        assert_eq!(set.len(), 65535);
+        assert!(!set.has(Pixel::new(x, 3)));
+
+        set.add(Pixel::new(x, 3));
+        set.validate_invariants().expect("Completing a full-width row has invalid invariants");
+        assert_eq!(set, full);
+    }
+}
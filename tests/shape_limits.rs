@@ -0,0 +1,167 @@
+use pixelset::{Pixel, Shape};
+use proptest::prelude::*;
+
+use pixelset::shapes::{
+    Arc, CubicBezier, Ellipse, EllipseOutline, Line, LineCap, Polyline, QuadraticBezier, Rectangle,
+    RectangleOutline, RegularPolygon, RegularPolygonOutline, RotatedEllipse, RotatedRectangle,
+    RoundedRectangle, RoundedRectangleOutline, Star, StarOutline, Triangle, TriangleOutline,
+};
+
+/// First coordinate of the window checked pixel by pixel, against the far edge.
+const EDGE: u16 = u16::MAX - 63;
+
+/// Coordinates close to the far edge of the coordinate space.
+fn edge() -> impl Strategy<Value = u16> {
+    EDGE..=u16::MAX
+}
+
+/// Sizes that are either small or reach past the coordinate limit from anywhere.
+fn size() -> impl Strategy<Value = u16> {
+    prop_oneof![0..96u16, (u16::MAX - 8)..=u16::MAX]
+}
+
+/// Corner counts that are either small or around the values where doubling them
+/// overflows a `u16`.
+fn count() -> impl Strategy<Value = u16> {
+    prop_oneof![0..10u16, 32760..=u16::MAX]
+}
+
+/// Strokes around the values where doubling them overflows a `u16`.
+fn stroke() -> impl Strategy<Value = u16> {
+    prop_oneof![0..8u16, 32760..=32775u16, (u16::MAX - 4)..=u16::MAX]
+}
+
+/// Checks that every method agrees with `set` near the far edge, without panicking.
+fn assert_consistent_at_edge(shape: &impl Shape) {
+    let set = shape.set();
+    set.validate_invariants().expect("shape produced invalid runs");
+    assert_eq!(shape.len(), set.len());
+    assert_eq!(shape.is_empty(), set.is_empty());
+
+    match (shape.bounds(), set.bounds()) {
+        (Some(outer), Some(inner)) => assert!(
+            outer.0 <= inner.0 && outer.1 <= inner.1 && outer.2 >= inner.2 && outer.3 >= inner.3,
+            "bounds {:?} do not contain {:?}",
+            outer,
+            inner
+        ),
+        (_, None) => {}
+        (None, Some(inner)) => panic!("missing bounds around {:?}", inner),
+    }
+
+    for y in EDGE..=u16::MAX {
+        let spans: Vec<_> = shape.row_spans(y).collect();
+        let expected: Vec<_> = set.iter().filter(|p| p.y == y).map(|p| p.x).collect();
+        let pixels: Vec<_> = spans.iter().flat_map(|&(start, end)| start..=end).collect();
+        assert_eq!(pixels, expected, "row {}", y);
+
+        for x in EDGE..=u16::MAX {
+            let pixel = Pixel::new(x, y);
+            assert_eq!(shape.has(pixel), set.has(pixel), "mismatch at ({}, {})", x, y);
+        }
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn rectangle_at_edge(x in edge(), y in edge(), width in size(), height in size()) {
+        let rectangle = Rectangle { x, y, width, height };
+        assert_consistent_at_edge(&rectangle);
+
+        let visible = (u16::MAX - x).min(width.saturating_sub(1)) as usize + 1;
+        let rows = (u16::MAX - y).min(height.saturating_sub(1)) as usize + 1;
+        let expected = if width == 0 || height == 0 { 0 } else { visible * rows };
+        prop_assert_eq!(rectangle.len(), expected);
+    }
+
+    #[test]
+    fn rectangle_outline_at_edge(
+        x in edge(), y in edge(), width in size(), height in size(), stroke in stroke()
+    ) {
+        let outline = RectangleOutline { x, y, width, height, stroke };
+        assert_consistent_at_edge(&outline);
+
+        // Only the top and left sides of the stroke are ever clipped away.
+        if width > 0 && height > 0 && stroke > 0 {
+            prop_assert!(outline.has(Pixel::new(x, y)));
+        }
+    }
+
+    #[test]
+    fn ellipse_at_edge(
+        x in edge(), y in edge(), width in size(), height in size(), stroke in stroke()
+    ) {
+        assert_consistent_at_edge(&Ellipse { x, y, width, height });
+        assert_consistent_at_edge(&EllipseOutline { x, y, width, height, stroke });
+    }
+
+    #[test]
+    fn arc_at_edge(x in edge(), y in edge(), width in size(), start in -7.0f32..7.0, sweep in -7.0f32..7.0) {
+        assert_consistent_at_edge(&Arc { x, y, width, height: width, stroke: 2, start, sweep });
+    }
+
+    #[test]
+    fn rounded_rectangle_at_edge(
+        x in edge(), y in edge(), width in size(), height in size(),
+        radius in 0..64u16, stroke in stroke()
+    ) {
+        assert_consistent_at_edge(&RoundedRectangle { x, y, width, height, radius });
+        assert_consistent_at_edge(&RoundedRectangleOutline { x, y, width, height, radius, stroke });
+    }
+
+    #[test]
+    fn line_at_edge(
+        x0 in edge(), y0 in edge(), x1 in edge(), y1 in edge(),
+        width in 0..12u16, square in any::<bool>()
+    ) {
+        let cap = if square { LineCap::Square } else { LineCap::Round };
+        let line = Line::new(Pixel::new(x0, y0), Pixel::new(x1, y1)).with_width(width).with_cap(cap);
+        assert_consistent_at_edge(&line);
+    }
+
+    #[test]
+    fn curves_at_edge(
+        points in prop::collection::vec((edge(), edge()), 4),
+        width in 0..12u16, square in any::<bool>()
+    ) {
+        let cap = if square { LineCap::Square } else { LineCap::Round };
+        let [a, b, c, d] = [0, 1, 2, 3].map(|i| Pixel::new(points[i].0, points[i].1));
+        assert_consistent_at_edge(&QuadraticBezier::new(a, b, c).with_width(width).with_cap(cap));
+        assert_consistent_at_edge(&CubicBezier::new(a, b, c, d).with_width(width).with_cap(cap));
+        assert_consistent_at_edge(&Polyline::new(vec![a, b, c, d]).with_width(width).with_cap(cap));
+    }
+
+    #[test]
+    fn polygons_at_edge(
+        x in edge(), y in edge(), radius in 0..96u16, sides in count(), rotation in -7.0f32..7.0,
+        stroke in stroke()
+    ) {
+        let center = Pixel::new(x, y);
+        assert_consistent_at_edge(&RegularPolygon { center, radius, sides, rotation });
+        assert_consistent_at_edge(&RegularPolygonOutline { center, radius, sides, rotation, stroke });
+        assert_consistent_at_edge(&Star { center, radius, points: sides, inner_ratio: 0.5, rotation });
+        assert_consistent_at_edge(&StarOutline { center, radius, points: sides, inner_ratio: 0.5, rotation, stroke });
+
+        let far = Pixel::new(x.saturating_add(radius), y.saturating_sub(radius));
+        let corner = Pixel::new(u16::MAX, u16::MAX);
+        assert_consistent_at_edge(&Triangle { a: center, b: far, c: corner });
+        assert_consistent_at_edge(&TriangleOutline { a: center, b: far, c: corner, stroke });
+    }
+
+    #[test]
+    fn rotated_shapes_at_edge(
+        x in edge(), y in edge(), width in 0..96u16, height in 0..96u16, angle in -7.0f32..7.0
+    ) {
+        let center = (x as f32 + 0.5, y as f32 + 0.5);
+        assert_consistent_at_edge(&RotatedRectangle::from_angle(center, width, height, angle));
+        assert_consistent_at_edge(&RotatedEllipse::from_angle(center, width, height, angle));
+    }
+
+    #[test]
+    fn translate_at_edge(x in edge(), y in edge(), width in size(), dx in -96i32..96, dy in -96i32..96) {
+        let rectangle = Rectangle { x, y, width, height: width };
+        assert_consistent_at_edge(&rectangle.translate(dx, dy));
+    }
+}
@@ -0,0 +1,729 @@
+use pixelset::{Direction, Pixel, PixelSet, Shape};
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI, TAU};
+
+use pixelset::shapes::{
+    Arc, CubicBezier, Ellipse, EllipseOutline, Line, LineCap, Polyline, QuadraticBezier, Rectangle,
+    RectangleOutline, RegularPolygon, RegularPolygonOutline, RotatedEllipse, RotatedRectangle,
+    RoundedRectangle, RoundedRectangleOutline, Star, StarOutline, Triangle, TriangleOutline,
+};
+
+/// Checks that `has`, `len`, `iter_pixels`, `bounds` and `row_spans` agree with `set`
+/// within a window.
+fn assert_consistent(shape: &impl Shape, window: u16) {
+    let set = shape.set();
+    set.validate_invariants().expect("shape produced invalid runs");
+    assert_eq!(shape.len(), set.len());
+    assert_eq!(shape.is_empty(), set.is_empty());
+    assert_eq!(PixelSet::new(shape.iter_pixels().collect()), set);
+
+    match (shape.bounds(), set.bounds()) {
+        (Some(outer), Some(inner)) => assert!(
+            outer.0 <= inner.0 && outer.1 <= inner.1 && outer.2 >= inner.2 && outer.3 >= inner.3,
+            "bounds {:?} do not contain {:?}",
+            outer,
+            inner
+        ),
+        (_, None) => {}
+        (None, Some(inner)) => panic!("missing bounds around {:?}", inner),
+    }
+
+    for y in 0..window {
+        let spans: Vec<_> = shape.row_spans(y).collect();
+        assert!(spans.windows(2).all(|w| w[0].1 as u32 + 1 < w[1].0 as u32), "row {} spans {:?}", y, spans);
+        let pixels: Vec<_> = spans.iter().flat_map(|&(s, e)| (s..=e).map(move |x| Pixel::new(x, y))).collect();
+        let expected: Vec<_> = set.iter().filter(|p| p.y == y).collect();
+        assert_eq!(pixels, expected, "row {}", y);
+    }
+
+    for y in 0..window {
+        for x in 0..window {
+            let pixel = Pixel::new(x, y);
+            assert_eq!(shape.has(pixel), set.has(pixel), "mismatch at ({}, {})", x, y);
+        }
+    }
+}
+
+#[test]
+fn test_line_axis_aligned() {
+    let horizontal = Line::new(Pixel::new(2, 3), Pixel::new(9, 3));
+    assert_eq!(horizontal.len(), 8);
+    assert!(horizontal.has(Pixel::new(9, 3)));
+    assert!(!horizontal.has(Pixel::new(10, 3)));
+
+    let vertical = Line::new(Pixel::new(4, 8), Pixel::new(4, 1));
+    assert_eq!(vertical.len(), 8);
+    assert!(vertical.has(Pixel::new(4, 1)));
+
+    assert_eq!(Line::new(Pixel::new(5, 5), Pixel::new(5, 5)).len(), 1);
+}
+
+#[test]
+fn test_line_bresenham() {
+    let line = Line::new(Pixel::new(0, 0), Pixel::new(6, 3));
+    let set = line.set();
+    assert_eq!(set.len(), 7);
+    for x in 0..=6 {
+        assert_eq!(set.iter().filter(|p| p.x == x).count(), 1, "column {}", x);
+    }
+
+    let diagonal = Line::new(Pixel::new(10, 0), Pixel::new(0, 10));
+    assert_eq!(diagonal.len(), 11);
+    assert!((0..=10).all(|i| diagonal.has(Pixel::new(10 - i, i))));
+
+    for (a, b) in [((1, 1), (12, 5)), ((3, 14), (9, 0)), ((0, 7), (15, 7))] {
+        let line = Line::new(Pixel::new(a.0, a.1), Pixel::new(b.0, b.1));
+        assert!(line.has(line.start) && line.has(line.end));
+        assert_consistent(&line, 16);
+    }
+
+    // Row spans are computed without walking the line, in every octant.
+    for i in 0..=16 {
+        for end in [(i, 0), (16, i), (16 - i, 16), (0, 16 - i)] {
+            assert_consistent(&Line::new(Pixel::new(8, 8), Pixel::new(end.0, end.1)), 17);
+            assert_consistent(&Line::new(Pixel::new(end.0, end.1), Pixel::new(8, 8)), 17);
+        }
+    }
+}
+
+#[test]
+fn test_thick_line_round_cap() {
+    let line = Line::new(Pixel::new(5, 5), Pixel::new(10, 5)).with_width(3);
+    let set = line.set();
+
+    assert_eq!(set.bounds(), Some((4, 4, 11, 6)));
+    assert_eq!(set.len(), 24);
+    assert_consistent(&line, 16);
+
+    let dot = Line::new(Pixel::new(8, 8), Pixel::new(8, 8)).with_width(5);
+    assert_eq!(dot.set().bounds(), Some((6, 6, 10, 10)));
+    assert!(!dot.has(Pixel::new(6, 6)));
+}
+
+#[test]
+fn test_thick_line_square_cap() {
+    let line = Line::new(Pixel::new(2, 5), Pixel::new(6, 5))
+        .with_width(3)
+        .with_cap(LineCap::Square);
+
+    assert_eq!(line.set().bounds(), Some((1, 4, 7, 6)));
+    assert_eq!(line.len(), 21);
+
+    let diagonal = Line::new(Pixel::new(3, 3), Pixel::new(12, 9))
+        .with_width(4)
+        .with_cap(LineCap::Square);
+    assert_consistent(&diagonal, 16);
+    assert!(diagonal.len() > Line::new(Pixel::new(3, 3), Pixel::new(12, 9)).with_width(4).len());
+}
+
+#[test]
+fn test_thick_line_clips_at_origin() {
+    let line = Line::new(Pixel::new(0, 0), Pixel::new(5, 0)).with_width(4);
+    let set = line.set();
+    assert_eq!(set.bounds().map(|b| (b.0, b.1)), Some((0, 0)));
+    assert_consistent(&line, 10);
+}
+
+#[test]
+fn test_line_width_zero_is_empty() {
+    let line = Line::new(Pixel::new(0, 0), Pixel::new(5, 5)).with_width(0);
+    assert!(line.is_empty());
+    assert!(line.set().is_empty());
+}
+
+#[test]
+fn test_line_from_direction() {
+    let east = Line::from_direction(Pixel::new(2, 2), Direction::new(1.0, 0.0).unwrap(), 5.0);
+    assert_eq!(east.end, Pixel::new(7, 2));
+
+    let north_west = Line::from_direction(Pixel::new(2, 2), Direction::new(-1.0, -1.0).unwrap(), 10.0);
+    assert_eq!(north_west.end, Pixel::new(0, 0));
+}
+
+#[test]
+fn test_polyline() {
+    let path = Polyline::new(vec![Pixel::new(0, 0), Pixel::new(3, 0), Pixel::new(3, 3)]);
+    assert_eq!(path.len(), 7);
+    assert_eq!(path.segments().count(), 2);
+    assert_consistent(&path, 8);
+
+    let thick = Polyline::new(vec![Pixel::new(2, 2), Pixel::new(10, 4), Pixel::new(3, 12)]).with_width(3);
+    assert_consistent(&thick, 16);
+    assert_consistent(&thick.clone().with_cap(LineCap::Square), 16);
+
+    assert_eq!(Polyline::new(vec![Pixel::new(4, 4)]).len(), 1);
+    assert!(Polyline::new(Vec::new()).is_empty());
+}
+
+#[test]
+fn test_polyline_square_caps_only_at_ends() {
+    let (start, joint, end) = (Pixel::new(2, 10), Pixel::new(10, 10), Pixel::new(10, 2));
+    let bent = Polyline::new(vec![start, joint, end]).with_width(5).with_cap(LineCap::Square);
+
+    // The ends are squared off, but the joint stays round.
+    assert!(bent.has(Pixel::new(0, 8)) && bent.has(Pixel::new(12, 0)));
+    assert!(!bent.has(Pixel::new(12, 12)));
+    assert!(bent.set().is_subset(&Rectangle { x: 0, y: 0, width: 13, height: 13 }.set()));
+    assert_consistent(&bent, 16);
+
+    let round = Polyline { cap: LineCap::Round, ..bent.clone() };
+    assert!(!round.has(Pixel::new(0, 8)));
+    assert!(round.set().is_subset(&bent.set()));
+
+    // Straight and single-point polylines match the equivalent line.
+    for (a, b) in [(start, joint), (joint, joint)] {
+        let points = if a == b { vec![a] } else { vec![a, b] };
+        for cap in [LineCap::Round, LineCap::Square] {
+            let line = Line::new(a, b).with_width(5).with_cap(cap);
+            assert_eq!(Polyline::new(points.clone()).with_width(5).with_cap(cap).set(), line.set());
+        }
+    }
+}
+
+/// Whether every pixel of the set is reachable from any other through 8-neighbours.
+fn is_connected(set: &PixelSet) -> bool {
+    let Some(first) = set.iter().next() else {
+        return true;
+    };
+
+    let mut seen = vec![first];
+    let mut stack = vec![first];
+    while let Some(p) = stack.pop() {
+        for dy in -1i32..=1 {
+            for dx in -1i32..=1 {
+                let (x, y) = (p.x as i32 + dx, p.y as i32 + dy);
+                if x < 0 || y < 0 {
+                    continue;
+                }
+                let next = Pixel::new(x as u16, y as u16);
+                if set.has(next) && !seen.contains(&next) {
+                    seen.push(next);
+                    stack.push(next);
+                }
+            }
+        }
+    }
+    seen.len() == set.len()
+}
+
+#[test]
+fn test_bezier_straight_control() {
+    let curve = QuadraticBezier::new(Pixel::new(0, 3), Pixel::new(5, 3), Pixel::new(10, 3));
+    assert_eq!(curve.set(), Line::new(Pixel::new(0, 3), Pixel::new(10, 3)).set());
+}
+
+#[test]
+fn test_quadratic_bezier() {
+    let curve = QuadraticBezier::new(Pixel::new(0, 0), Pixel::new(10, 20), Pixel::new(20, 0));
+    let set = curve.set();
+
+    assert!(set.has(Pixel::new(0, 0)) && set.has(Pixel::new(20, 0)));
+    assert!(set.has(Pixel::new(10, 10)), "curve should pass through its midpoint");
+    assert!(!set.has(Pixel::new(10, 20)), "curve should not reach its control point");
+    assert!(is_connected(&set));
+    assert_consistent(&curve, 24);
+
+    let thick = curve.with_width(4);
+    assert!(set.is_subset(&thick.set()));
+    assert_consistent(&thick, 24);
+}
+
+#[test]
+fn test_cubic_bezier() {
+    let curve = CubicBezier::new(Pixel::new(2, 20), Pixel::new(2, 0), Pixel::new(30, 40), Pixel::new(30, 10));
+    let set = curve.set();
+
+    assert!(set.has(curve.start) && set.has(curve.end));
+    assert!(is_connected(&set));
+    assert_consistent(&curve, 40);
+
+    let round = curve.with_width(5);
+    let square = round.with_cap(LineCap::Square);
+    assert!(round.set().is_subset(&square.set()));
+    assert!(square.len() > round.len());
+    assert_consistent(&square, 40);
+}
+
+#[test]
+fn test_bezier_degenerate() {
+    let p = Pixel::new(6, 6);
+    assert_eq!(CubicBezier::new(p, p, p, p).len(), 1);
+    assert_eq!(QuadraticBezier::new(p, p, p).with_width(3).set(), Line::new(p, p).with_width(3).set());
+    assert!(CubicBezier::new(p, Pixel::new(0, 0), Pixel::new(9, 9), p).with_width(0).is_empty());
+
+    // A loop returning to its start is still one connected curve.
+    let looped = CubicBezier::new(p, Pixel::new(20, 0), Pixel::new(20, 12), p);
+    assert!(looped.len() > 10);
+    assert!(is_connected(&looped.set()));
+}
+
+#[test]
+fn test_arc_full_sweep_is_outline() {
+    let outline = EllipseOutline { x: 2, y: 3, width: 20, height: 14, stroke: 2 };
+    let arc = Arc { x: 2, y: 3, width: 20, height: 14, stroke: 2, start: 1.0, sweep: TAU };
+    assert_eq!(arc.set(), outline.set());
+    assert_eq!(Arc { sweep: -7.0, ..arc }.set(), outline.set());
+}
+
+#[test]
+fn test_arc_quadrants() {
+    let arc = Arc { x: 0, y: 0, width: 20, height: 20, stroke: 3, start: 0.0, sweep: FRAC_PI_2 };
+    let set = arc.set();
+    assert!(!set.is_empty());
+    assert!(set.iter().all(|p| p.x >= 10 && p.y < 10), "counterclockwise from east is the top right");
+    assert_consistent(&arc, 20);
+
+    let clockwise = Arc { sweep: -FRAC_PI_2, ..arc }.set();
+    assert!(clockwise.iter().all(|p| p.x >= 10 && p.y >= 10), "clockwise from east is the bottom right");
+    assert_eq!(clockwise.len(), set.len());
+
+    let top = Arc { start: 0.0, sweep: PI, ..arc }.set();
+    let bottom = Arc { start: PI, sweep: PI, ..arc }.set();
+    let full = EllipseOutline { x: 0, y: 0, width: 20, height: 20, stroke: 3 }.set();
+    assert_eq!(top.or(&bottom), full);
+}
+
+#[test]
+fn test_arc_empty() {
+    let arc = Arc { x: 0, y: 0, width: 10, height: 10, stroke: 2, start: 0.0, sweep: 0.0 };
+    assert!(arc.is_empty());
+    assert!(!arc.has(Pixel::new(5, 0)));
+    assert!(Arc { sweep: PI, stroke: 0, ..arc }.is_empty());
+}
+
+#[test]
+fn test_rotated_rectangle_axis_aligned() {
+    let expected = Rectangle { x: 7, y: 8, width: 6, height: 4 }.set();
+    for angle in [0.0, PI] {
+        let rect = RotatedRectangle::from_angle((10.0, 10.0), 6, 4, angle);
+        assert_eq!(rect.set(), expected, "angle {}", angle);
+        assert_eq!(rect.len(), 24);
+    }
+
+    let expected = Rectangle { x: 8, y: 7, width: 4, height: 6 }.set();
+    for angle in [FRAC_PI_2, -FRAC_PI_2] {
+        assert_eq!(RotatedRectangle::from_angle((10.0, 10.0), 6, 4, angle).set(), expected);
+    }
+
+    // Centered on a pixel, odd sizes are symmetric about it.
+    let rect = RotatedRectangle::new((5.5, 5.5), 3, 5, Direction::new(0.0, 1.0).unwrap());
+    assert_eq!(rect.set(), Rectangle { x: 3, y: 4, width: 5, height: 3 }.set());
+}
+
+#[test]
+fn test_rotated_rectangle_diagonal() {
+    let rect = RotatedRectangle::from_angle((20.0, 20.0), 20, 6, FRAC_PI_4);
+    let set = rect.set();
+
+    // Roughly its area, and leaning from bottom left to top right.
+    assert!((set.len() as i32 - 120).abs() <= 12, "len {}", set.len());
+    assert!(rect.has(Pixel::new(25, 14)) && rect.has(Pixel::new(14, 25)));
+    assert!(!rect.has(Pixel::new(14, 14)) && !rect.has(Pixel::new(25, 25)));
+    assert_consistent(&rect, 40);
+
+    assert!(RotatedRectangle::from_angle((5.0, 5.0), 0, 6, 1.0).is_empty());
+    assert!(RotatedRectangle::from_angle((5.0, 5.0), 6, 0, 1.0).is_empty());
+}
+
+#[test]
+fn test_rotated_ellipse_matches_ellipse() {
+    for (x, y, width, height) in [(0, 0, 10, 6), (3, 2, 7, 11), (1, 1, 12, 12)] {
+        let ellipse = Ellipse { x, y, width, height };
+        let center = (x as f32 + width as f32 / 2.0, y as f32 + height as f32 / 2.0);
+        let rotated = RotatedEllipse::from_angle(center, width, height, 0.0);
+        assert_eq!(rotated.set(), ellipse.set(), "{:?}", ellipse);
+
+        let turned = RotatedEllipse::from_angle(center, height, width, FRAC_PI_2);
+        assert_eq!(turned.set(), ellipse.set(), "{:?} turned", ellipse);
+    }
+}
+
+#[test]
+fn test_rotated_ellipse_diagonal() {
+    let ellipse = RotatedEllipse::new((16.0, 16.0), 24, 8, Direction::new(1.0, 1.0).unwrap());
+    let set = ellipse.set();
+
+    // Area is π * 12 * 4.
+    assert!((set.len() as f32 - PI * 48.0).abs() < 10.0, "len {}", set.len());
+    assert!(ellipse.has(Pixel::new(22, 22)) && ellipse.has(Pixel::new(9, 9)));
+    assert!(!ellipse.has(Pixel::new(22, 9)) && !ellipse.has(Pixel::new(9, 22)));
+    assert_consistent(&ellipse, 32);
+
+    // Clipped at the origin.
+    let clipped = RotatedEllipse::from_angle((1.0, 1.0), 10, 4, 0.5);
+    assert_eq!(clipped.set().bounds().map(|b| (b.0, b.1)), Some((0, 0)));
+    assert_consistent(&clipped, 8);
+
+    assert!(RotatedEllipse::from_angle((5.0, 5.0), 0, 4, 0.0).is_empty());
+}
+
+/// Whether the set is symmetric under mirroring about the vertical line through column `x`.
+fn is_mirror_symmetric(set: &PixelSet, x: u16) -> bool {
+    set.iter().all(|p| set.has(Pixel::new(2 * x - p.x, p.y)))
+}
+
+#[test]
+fn test_triangle() {
+    let (a, b, c) = (Pixel::new(0, 0), Pixel::new(8, 0), Pixel::new(0, 8));
+    let triangle = Triangle { a, b, c };
+
+    assert_eq!(triangle.len(), 45);
+    assert!(triangle.has(a) && triangle.has(b) && triangle.has(c));
+    assert!(triangle.has(Pixel::new(4, 4)) && !triangle.has(Pixel::new(5, 4)));
+    assert_eq!(Triangle { a, b: c, c: b }.set(), triangle.set());
+    assert_consistent(&triangle, 10);
+
+    let slanted = Triangle { a: Pixel::new(3, 1), b: Pixel::new(17, 6), c: Pixel::new(6, 15) };
+    assert_consistent(&slanted, 20);
+
+    let collinear = Triangle { a: Pixel::new(0, 0), b: Pixel::new(2, 2), c: Pixel::new(5, 5) };
+    assert!(collinear.is_empty());
+}
+
+#[test]
+fn test_triangle_outline() {
+    let (a, b, c) = (Pixel::new(0, 0), Pixel::new(12, 0), Pixel::new(0, 12));
+    let fill = Triangle { a, b, c }.set();
+    let outline = TriangleOutline { a, b, c, stroke: 1 };
+    let set = outline.set();
+
+    assert!(set.is_subset(&fill));
+    assert!((0..=12).all(|i| set.has(Pixel::new(i, 0)) && set.has(Pixel::new(0, i))));
+    assert!(!set.has(Pixel::new(1, 1)) && !set.has(Pixel::new(3, 5)));
+    assert!(is_connected(&set));
+    assert_consistent(&outline, 14);
+
+    assert_eq!(TriangleOutline { a, b, c, stroke: 20 }.set(), fill);
+    assert!(TriangleOutline { a, b, c, stroke: 0 }.is_empty());
+}
+
+#[test]
+fn test_regular_polygon() {
+    let hexagon = RegularPolygon { center: Pixel::new(20, 20), radius: 12, sides: 6, rotation: 0.0 };
+    let set = hexagon.set();
+
+    // Area of a regular hexagon is 3√3/2 r².
+    assert!((set.len() as f32 - 2.598 * 144.0).abs() < 25.0, "len {}", set.len());
+    assert!(hexagon.has(Pixel::new(20, 8)), "first corner points up");
+    assert!(is_mirror_symmetric(&set, 20));
+    assert_consistent(&hexagon, 40);
+
+    let turned = RegularPolygon { rotation: FRAC_PI_2, ..hexagon };
+    assert!(turned.has(Pixel::new(9, 20)) && !turned.has(Pixel::new(20, 8)));
+
+    assert!(RegularPolygon { sides: 2, ..hexagon }.is_empty());
+    assert!(RegularPolygon { radius: 0, ..hexagon }.is_empty());
+}
+
+#[test]
+fn test_regular_polygon_outline() {
+    let (center, radius, sides, rotation) = (Pixel::new(16, 16), 12, 5, 0.0);
+    let fill = RegularPolygon { center, radius, sides, rotation }.set();
+    let outline = RegularPolygonOutline { center, radius, sides, rotation, stroke: 2 };
+    let set = outline.set();
+
+    assert!(set.is_subset(&fill));
+    assert!(!set.has(center));
+    assert!(is_connected(&set));
+    assert!(is_mirror_symmetric(&set, 16));
+    assert_consistent(&outline, 32);
+
+    assert_eq!(RegularPolygonOutline { stroke: 12, ..outline }.set(), fill);
+}
+
+#[test]
+fn test_regular_polygon_with_many_sides() {
+    let polygon = RegularPolygon { center: Pixel::new(20, 20), radius: 12, sides: u16::MAX, rotation: 0.0 };
+    let set = polygon.set();
+
+    // So many sides approach a circle through the corners.
+    assert!(polygon.has(Pixel::new(20, 8)), "first corner points up");
+    assert!((set.len() as f32 - PI * 144.0).abs() < 25.0, "len {}", set.len());
+    assert!(is_mirror_symmetric(&set, 20));
+    assert_consistent(&polygon, 40);
+
+    let RegularPolygon { center, radius, sides, rotation } = polygon;
+    let outline = RegularPolygonOutline { center, radius, sides, rotation, stroke: 2 };
+    assert!(outline.set().is_subset(&set));
+    assert!(!outline.has(center) && outline.has(Pixel::new(20, 8)));
+    assert_consistent(&outline, 40);
+}
+
+#[test]
+fn test_star() {
+    let star = Star { center: Pixel::new(20, 20), radius: 16, points: 5, inner_ratio: 0.4, rotation: 0.0 };
+    let set = star.set();
+
+    assert!(star.has(Pixel::new(20, 4)), "first tip points up");
+    assert!(star.has(Pixel::new(20, 20)));
+    assert!(!star.has(Pixel::new(20, 32)), "a notch points down");
+    assert!(is_mirror_symmetric(&set, 20));
+    assert!(is_connected(&set));
+    assert_consistent(&star, 40);
+
+    let pentagon = RegularPolygon { center: Pixel::new(20, 20), radius: 16, sides: 5, rotation: 0.0 };
+    assert!(set.is_subset(&pentagon.set()));
+    assert!(set.len() < pentagon.len());
+
+    assert!(Star { points: 1, ..star }.is_empty());
+    assert!(Star { inner_ratio: 0.0, ..star }.is_empty());
+}
+
+#[test]
+fn test_star_outline() {
+    let (center, radius, points, inner_ratio, rotation) = (Pixel::new(20, 20), 16, 6, 0.5, 0.3);
+    let fill = Star { center, radius, points, inner_ratio, rotation }.set();
+    let outline = StarOutline { center, radius, points, inner_ratio, rotation, stroke: 2 };
+    let set = outline.set();
+
+    assert!(set.is_subset(&fill));
+    assert!(!set.has(center));
+    assert!(is_connected(&set));
+    assert_consistent(&outline, 40);
+    assert!(StarOutline { stroke: 0, ..outline }.is_empty());
+}
+
+#[test]
+fn test_star_with_many_points() {
+    let star = Star { center: Pixel::new(10, 10), radius: 6, points: 40000, inner_ratio: 0.5, rotation: 0.0 };
+    assert_eq!(star.bounds(), Some((4, 4, 16, 16)));
+    assert!(star.has(Pixel::new(10, 10)) && star.has(Pixel::new(10, 4)));
+    assert_eq!(star.set().len(), star.len());
+
+    let Star { center, radius, points, inner_ratio, rotation } = star;
+    let outline = StarOutline { center, radius, points, inner_ratio, rotation, stroke: 1 };
+    assert_eq!(outline.bounds(), Some((4, 4, 16, 16)));
+    assert!(!outline.has(Pixel::new(10, 10)) && outline.has(Pixel::new(10, 4)));
+}
+
+#[test]
+fn test_rounded_rectangle() {
+    let square = RoundedRectangle { x: 2, y: 3, width: 10, height: 8, radius: 0 };
+    assert_eq!(square.set(), Rectangle { x: 2, y: 3, width: 10, height: 8 }.set());
+
+    let rounded = RoundedRectangle { radius: 3, ..square };
+    assert!(!rounded.has(Pixel::new(2, 3)) && !rounded.has(Pixel::new(11, 10)));
+    assert!(rounded.has(Pixel::new(5, 3)) && rounded.has(Pixel::new(2, 6)));
+    assert!(rounded.set().is_subset(&square.set()));
+    assert_consistent(&rounded, 16);
+
+    // An oversized radius gives a stadium, matching an ellipse on its short axis.
+    let stadium = RoundedRectangle { x: 0, y: 0, width: 20, height: 10, radius: 100 };
+    assert_eq!(
+        stadium.set().and(&Rectangle { x: 0, y: 0, width: 5, height: 10 }.set()),
+        Ellipse { x: 0, y: 0, width: 10, height: 10 }.set().and(&Rectangle { x: 0, y: 0, width: 5, height: 10 }.set()),
+    );
+
+    assert!(RoundedRectangle { width: 0, ..rounded }.is_empty());
+}
+
+#[test]
+fn test_rounded_rectangle_outline() {
+    let outline = RoundedRectangleOutline { x: 1, y: 1, width: 12, height: 9, radius: 0, stroke: 2 };
+    assert_eq!(outline.set(), RectangleOutline { x: 1, y: 1, width: 12, height: 9, stroke: 2 }.set());
+
+    let rounded = RoundedRectangleOutline { radius: 4, ..outline };
+    let fill = RoundedRectangle { x: 1, y: 1, width: 12, height: 9, radius: 4 }.set();
+    let set = rounded.set();
+    assert!(set.is_subset(&fill));
+    assert!(set.has(Pixel::new(6, 1)) && set.has(Pixel::new(6, 2)) && !set.has(Pixel::new(6, 3)));
+    assert!(is_connected(&set));
+    assert_consistent(&rounded, 16);
+
+    assert_eq!(RoundedRectangleOutline { stroke: 5, ..rounded }.set(), fill);
+    assert!(RoundedRectangleOutline { stroke: 0, ..rounded }.is_empty());
+}
+
+#[test]
+fn test_shape_union_intersection_difference() {
+    let a = Rectangle { x: 0, y: 0, width: 10, height: 10 };
+    let b = Ellipse { x: 5, y: 5, width: 12, height: 12 };
+    let (set_a, set_b) = (a.set(), b.set());
+
+    let union = (&a).union(&b);
+    assert_eq!(union.set(), set_a.or(&set_b));
+    assert_consistent(&union, 20);
+    assert_eq!(union.iter_pixels().count(), union.len(), "no pixel is yielded twice");
+
+    let intersection = (&a).intersection(&b);
+    assert_eq!(intersection.set(), set_a.and(&set_b));
+    assert_consistent(&intersection, 20);
+
+    let difference = a.difference(b);
+    assert_eq!(difference.set(), set_a.difference(&set_b));
+    assert_consistent(&difference, 20);
+
+    // Combinators nest.
+    let ring = Ellipse { x: 0, y: 0, width: 16, height: 16 }
+        .difference(Ellipse { x: 4, y: 4, width: 8, height: 8 })
+        .union(Line::new(Pixel::new(0, 8), Pixel::new(16, 8)));
+    assert!(ring.has(Pixel::new(8, 8)) && !ring.has(Pixel::new(8, 7)));
+    assert_consistent(&ring, 20);
+}
+
+#[test]
+fn test_shape_translate() {
+    let rect = Rectangle { x: 2, y: 3, width: 4, height: 2 };
+
+    let moved = rect.translate(5, -1);
+    assert_eq!(moved.set(), Rectangle { x: 7, y: 2, width: 4, height: 2 }.set());
+    assert!(moved.has(Pixel::new(7, 2)) && !moved.has(Pixel::new(2, 3)));
+    assert_consistent(&moved, 12);
+
+    // Pixels leaving the coordinate space are dropped.
+    let clipped = rect.translate(-4, -4);
+    assert_eq!(clipped.set(), Rectangle { x: 0, y: 0, width: 2, height: 1 }.set());
+    assert_eq!(clipped.len(), 2);
+    assert_consistent(&clipped, 8);
+
+    let edge = Rectangle { x: 65530, y: 0, width: 5, height: 1 }.translate(3, 0);
+    assert_eq!(edge.len(), 3);
+    assert!(edge.has(Pixel::new(65535, 0)));
+    assert_eq!(edge.iter_pixels().count(), 3);
+}
+
+#[test]
+fn test_shape_clip_to() {
+    let circle = Ellipse { x: 0, y: 0, width: 20, height: 20 };
+    let bounds = Rectangle { x: 0, y: 0, width: 10, height: 8 };
+
+    let clipped = circle.clip_to(bounds);
+    assert_eq!(clipped.set(), circle.set().and(&bounds.set()));
+    assert!(clipped.set().bounds().is_some_and(|(_, _, max_x, max_y)| max_x < 10 && max_y < 8));
+    assert_consistent(&clipped, 24);
+}
+
+#[test]
+fn test_pixelset_translate() {
+    let set = PixelSet::new(vec![Pixel::new(0, 0), Pixel::new(3, 1), Pixel::new(4, 1), Pixel::new(65535, 2)]);
+
+    let moved = set.translate(1, 1);
+    assert_eq!(moved, PixelSet::new(vec![Pixel::new(1, 1), Pixel::new(4, 2), Pixel::new(5, 2)]));
+    assert_eq!(set.translate(0, 0), set);
+    assert!(set.translate(0, 70000).is_empty());
+
+    let row = Rectangle { x: 1, y: 0, width: 65535, height: 1 }.set();
+    let full = row.translate(-1, 0);
+    full.validate_invariants().unwrap();
+    assert_eq!(full.len(), 65535);
+}
+
+/// A shape described only by its bounds and row spans.
+struct Diamond {
+    center: Pixel,
+    radius: u16,
+}
+
+impl Shape for Diamond {
+    fn bounds(&self) -> Option<(u16, u16, u16, u16)> {
+        let Pixel { x, y } = self.center;
+        Some((x - self.radius, y - self.radius, x + self.radius, y + self.radius))
+    }
+
+    fn row_spans(&self, y: u16) -> impl Iterator<Item = (u16, u16)> {
+        let dy = self.center.y.abs_diff(y);
+        let half = self.radius.checked_sub(dy);
+        half.map(|half| (self.center.x - half, self.center.x + half)).into_iter()
+    }
+}
+
+#[test]
+fn test_shape_from_row_spans() {
+    let diamond = Diamond { center: Pixel::new(10, 10), radius: 4 };
+
+    assert_eq!(diamond.len(), 41);
+    assert!(diamond.has(Pixel::new(10, 6)) && diamond.has(Pixel::new(12, 8)));
+    assert!(!diamond.has(Pixel::new(13, 8)) && !diamond.has(Pixel::new(20, 20)));
+    assert!(!diamond.is_empty());
+    assert_consistent(&diamond, 20);
+
+    // Defaults compose with the combinators.
+    let cut = Diamond { center: Pixel::new(10, 10), radius: 4 }.difference(Rectangle { x: 9, y: 0, width: 3, height: 20 });
+    assert_eq!(cut.len(), 41 - 9 - 2 * 7);
+    assert_consistent(&cut, 20);
+}
+
+#[test]
+fn test_builtin_shapes_row_spans() {
+    assert_consistent(&Rectangle { x: 3, y: 2, width: 7, height: 5 }, 12);
+    assert_consistent(&RectangleOutline { x: 1, y: 1, width: 9, height: 7, stroke: 2 }, 12);
+    assert_consistent(&RectangleOutline { x: 1, y: 1, width: 4, height: 7, stroke: 2 }, 12);
+    assert_consistent(&Ellipse { x: 2, y: 1, width: 13, height: 9 }, 16);
+    assert_consistent(&EllipseOutline { x: 0, y: 0, width: 15, height: 12, stroke: 3 }, 16);
+    assert_consistent(&Arc { x: 0, y: 0, width: 15, height: 15, stroke: 2, start: 0.5, sweep: 4.0 }, 16);
+
+    // Ellipse lengths are exact rather than bounding-box areas.
+    let ellipse = Ellipse { x: 0, y: 0, width: 10, height: 10 };
+    assert_eq!(ellipse.len(), ellipse.iter_pixels().count());
+    assert!(ellipse.len() < 100);
+
+    let outline = EllipseOutline { x: 0, y: 0, width: 10, height: 10, stroke: 2 };
+    assert_eq!(outline.len(), outline.iter_pixels().count());
+}
+
+/// Checks that `signed_distance` is non-positive exactly at the centers of the shape's pixels.
+fn assert_distance_sign(shape: &impl Shape, window: u16) {
+    for y in 0..window {
+        for x in 0..window {
+            let distance = shape.signed_distance(x as f32 + 0.5, y as f32 + 0.5);
+            if shape.has(Pixel::new(x, y)) {
+                assert!(distance <= 1e-4, "({}, {}) is inside but {} away", x, y, distance);
+            } else {
+                assert!(distance >= -1e-4, "({}, {}) is outside but {} away", x, y, distance);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_signed_distance_rectangle() {
+    let rectangle = Rectangle { x: 2, y: 2, width: 4, height: 6 };
+    assert_eq!(rectangle.signed_distance(4.0, 5.0), -2.0);
+    assert_eq!(rectangle.signed_distance(0.0, 5.0), 2.0);
+    assert_eq!(rectangle.signed_distance(2.0, 3.0), 0.0);
+    assert_eq!(rectangle.signed_distance(-1.0, -2.0), 5.0);
+    assert_eq!(Rectangle { x: 2, y: 2, width: 0, height: 6 }.signed_distance(2.0, 2.0), f32::INFINITY);
+
+    let outline = RectangleOutline { x: 0, y: 0, width: 10, height: 10, stroke: 2 };
+    assert_eq!(outline.signed_distance(5.0, 5.0), 3.0);
+    assert_eq!(outline.signed_distance(5.0, 1.0), -1.0);
+    assert_eq!(outline.signed_distance(5.0, -1.0), 1.0);
+}
+
+#[test]
+fn test_signed_distance_ellipse() {
+    // Circles are measured exactly.
+    let circle = Ellipse { x: 0, y: 0, width: 20, height: 20 };
+    assert!((circle.signed_distance(10.0, 2.0) + 2.0).abs() < 1e-5);
+    assert!((circle.signed_distance(10.0, -3.0) - 3.0).abs() < 1e-5);
+    assert_eq!(circle.signed_distance(10.0, 10.0), -10.0);
+
+    let ring = EllipseOutline { x: 0, y: 0, width: 20, height: 20, stroke: 3 };
+    assert!((ring.signed_distance(10.0, 10.0) - 7.0).abs() < 1e-5);
+    assert!((ring.signed_distance(10.0, 1.5) + 1.5).abs() < 1e-5);
+
+    // Other ellipses are zero on their edge.
+    let ellipse = Ellipse { x: 0, y: 0, width: 20, height: 10 };
+    assert!(ellipse.signed_distance(0.0, 5.0).abs() < 1e-5);
+    assert!(ellipse.signed_distance(10.0, 0.0).abs() < 1e-5);
+}
+
+#[test]
+fn test_signed_distance_matches_pixels() {
+    assert_distance_sign(&Rectangle { x: 3, y: 2, width: 7, height: 5 }, 12);
+    assert_distance_sign(&RectangleOutline { x: 1, y: 1, width: 9, height: 7, stroke: 2 }, 12);
+    assert_distance_sign(&Ellipse { x: 2, y: 1, width: 13, height: 9 }, 16);
+    assert_distance_sign(&EllipseOutline { x: 0, y: 0, width: 15, height: 12, stroke: 3 }, 16);
+
+    // Shapes without exact geometry measure to the edges of their pixels.
+    let triangle = Triangle { a: Pixel::new(1, 1), b: Pixel::new(12, 3), c: Pixel::new(4, 11) };
+    assert_distance_sign(&triangle, 16);
+    for (x, y) in (0..16).flat_map(|y| (0..16).map(move |x| (x as f32 + 0.5, y as f32 + 0.5))) {
+        assert!(triangle.signed_distance(x, y).abs() >= 0.5);
+    }
+    assert_eq!(triangle.signed_distance(1.5, 0.5), 0.5);
+    assert_eq!(triangle.signed_distance(1.0, 1.0), 0.0);
+
+    let cut = Ellipse { x: 0, y: 0, width: 12, height: 12 }.difference(Rectangle { x: 4, y: 0, width: 4, height: 12 });
+    assert_distance_sign(&cut, 14);
+    assert_eq!(cut.signed_distance(6.0, 6.0), 2.0);
+}
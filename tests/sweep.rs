@@ -0,0 +1,129 @@
+use pixelset::{Direction, Extent, Pixel, PixelSet, Shape};
+use pixelset::shapes::{Ellipse, Rectangle};
+
+fn rect(x: u16, y: u16, width: u16, height: u16) -> PixelSet {
+    Rectangle { x, y, width, height }.set()
+}
+
+fn assert_close(actual: f32, expected: f32) {
+    assert!((actual - expected).abs() < 1e-3, "{} != {}", actual, expected);
+}
+
+#[test]
+fn test_sweep_axis_aligned() {
+    let dot = PixelSet::new(vec![Pixel::new(3, 3)]);
+    let east = Direction::new(1.0, 0.0).unwrap();
+    assert_eq!(dot.sweep(east, 5.0), rect(3, 3, 6, 1));
+    assert_eq!(dot.sweep(east, -3.0), rect(0, 3, 4, 1));
+    assert_eq!(dot.sweep(east, 0.4), dot);
+
+    let south = Direction::new(0.0, 1.0).unwrap();
+    assert_eq!(rect(2, 2, 4, 3).sweep(south, 3.0), rect(2, 2, 4, 6));
+    assert_eq!(rect(2, 2, 4, 3).sweep(south.opposite(), 10.0), rect(2, 0, 4, 5));
+}
+
+#[test]
+fn test_sweep_diagonal() {
+    let dot = PixelSet::new(vec![Pixel::new(0, 0)]);
+    let swept = dot.sweep(Direction::new(1.0, 1.0).unwrap(), 4.0 * 2f32.sqrt());
+    let diagonal: Vec<_> = (0..5).map(|i| Pixel::new(i, i)).collect();
+    assert_eq!(swept, PixelSet::new(diagonal));
+
+    // Shallow sweeps of a single pixel trace a connected line.
+    let line = PixelSet::new(vec![Pixel::new(10, 10)]).sweep(Direction::from_angle(-0.3), 20.0);
+    assert_eq!(line.len(), 20);
+    assert!(line.has(Pixel::new(10, 10)) && line.has(Pixel::new(29, 16)));
+}
+
+#[test]
+fn test_sweep_matches_translations() {
+    let blob = Ellipse { x: 20, y: 20, width: 9, height: 6 }.set();
+    for angle in [0.2f32, 1.1, 2.0, 3.3, 4.4, 5.9] {
+        let dir = Direction::from_angle(angle);
+        let (ex, ey) = dir.scale(12.0);
+        let (ex, ey) = (ex.round(), ey.round());
+        let steps = ex.abs().max(ey.abs()) as i32;
+
+        let mut expected = blob.clone();
+        for step in 1..=steps {
+            let t = step as f32 / steps as f32;
+            expected = expected.or(&blob.translate((ex * t).round() as i32, (ey * t).round() as i32));
+        }
+        let swept = blob.sweep(dir, 12.0);
+        swept.validate_invariants().unwrap();
+        assert_eq!(swept, expected, "angle {}", angle);
+    }
+}
+
+#[test]
+fn test_sweep_coordinate_limit() {
+    let east = Direction::new(1.0, 0.0).unwrap();
+    assert_eq!(rect(u16::MAX - 2, 0, 3, 2).sweep(east, 100.0), rect(u16::MAX - 2, 0, 3, 2));
+    assert_eq!(rect(0, 0, 3, 2).sweep(east.opposite(), 100.0), rect(0, 0, 3, 2));
+
+    // Stretching a run across every column splits it like other full-width rows.
+    let full = rect(0, 4, u16::MAX, 1).sweep(east, 1.0);
+    full.validate_invariants().unwrap();
+    assert_eq!(full.len(), 65536);
+}
+
+#[test]
+fn test_swept_full_width_row_in_set_operations() {
+    let east = Direction::new(1.0, 0.0).unwrap();
+    let full = rect(0, 5, u16::MAX, 1).sweep(east, 1.0);
+    let block = rect(100, 4, 10, 3);
+
+    let union = full.or(&full).or(&block);
+    union.validate_invariants().unwrap();
+    assert_eq!(full.or(&full), full);
+    assert_eq!(union.len(), 65536 + 20);
+
+    let common = full.and(&block);
+    common.validate_invariants().unwrap();
+    assert_eq!(common, rect(100, 5, 10, 1));
+    assert_eq!(full.and(&full), full);
+
+    let rest = full.difference(&block);
+    rest.validate_invariants().unwrap();
+    assert_eq!(rest.len(), 65536 - 10);
+    assert_eq!(rest.or(&common), full);
+    assert!(full.difference(&full).is_empty());
+}
+
+#[test]
+fn test_extent_along() {
+    let block = rect(0, 0, 10, 4);
+    let east = Direction::new(1.0, 0.0).unwrap();
+    assert_eq!(block.extent_along(east), Some(Extent { min: 0.0, max: 10.0, width: 4.0 }));
+
+    let south = block.extent_along(Direction::new(0.0, 1.0).unwrap()).unwrap();
+    assert_close(south.min, 0.0);
+    assert_close(south.max, 4.0);
+    assert_close(south.width, 10.0);
+
+    // Looking west flips the projections.
+    let west = block.extent_along(east.opposite()).unwrap();
+    assert_close(west.min, -10.0);
+    assert_close(west.max, 0.0);
+
+    // A pixel square is widest along its diagonal.
+    let dot = PixelSet::new(vec![Pixel::new(0, 0)]);
+    let diagonal = dot.extent_along(Direction::new(1.0, 1.0).unwrap()).unwrap();
+    assert_close(diagonal.min, 0.0);
+    assert_close(diagonal.max, 2f32.sqrt());
+    assert_close(diagonal.width, 2f32.sqrt());
+
+    assert_eq!(PixelSet::empty().extent_along(east), None);
+}
+
+#[test]
+fn test_sweep_lengthens_extent() {
+    let blob = Ellipse { x: 30, y: 30, width: 12, height: 8 }.set();
+    let dir = Direction::new(0.0, -1.0).unwrap();
+    let before = blob.extent_along(dir).unwrap();
+    let after = blob.sweep(dir, 15.0).extent_along(dir).unwrap();
+
+    assert_close(after.min, before.min);
+    assert_close(after.max, before.max + 15.0);
+    assert_close(after.width, before.width);
+}
@@ -107,7 +107,7 @@ fn test_repeated_unions_no_corruption() {
             Pixel::new(i * 10, 0), Pixel::new(i * 10 + 1, 0), Pixel::new(i * 10 + 2, 0),
         ]);
         result = result.or(&set);
-        result.validate_invariants().expect(&format!("Iteration {} has invalid invariants", i));
+        result.validate_invariants().unwrap_or_else(|_| panic!("Iteration {} has invalid invariants", i));
     }
 
     assert_eq!(result.len(), 15, "Should have 5 * 3 pixels");